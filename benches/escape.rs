@@ -0,0 +1,69 @@
+//! Compares `write_escaped` with a plain byte-by-byte escaping loop.
+//! Run by `cargo bench --bench escape`.
+use cmark_syntax::write_escaped;
+use std::fmt::{self, Write};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Escaping one byte at a time, as `write_escaped` used to.
+fn write_escaped_bytewise(buf: &mut String, part: &str) -> fmt::Result {
+    let mut start = 0;
+
+    for (idx, byte) in part.bytes().enumerate() {
+        let replace = match byte {
+            b'<' => "&lt;",
+            b'>' => "&gt;",
+            b'&' => "&amp;",
+            b'"' => "&quot;",
+            b'\'' => "&#39;",
+            _ => continue,
+        };
+        buf.write_str(&part[start..idx])?;
+        buf.write_str(replace)?;
+
+        start = idx + 1;
+    }
+
+    buf.write_str(&part[start..])
+}
+
+fn measure(name: &str, input: &str, escape: impl Fn(&mut String, &str) -> fmt::Result) {
+    const ITERATIONS: u32 = 2000;
+    let mut buf = String::with_capacity(input.len() * 2);
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        buf.clear();
+        escape(&mut buf, black_box(input)).unwrap();
+        black_box(&buf);
+    }
+    let elapsed: Duration = start.elapsed() / ITERATIONS;
+
+    println!("{:<24} {:>10.2?} per iteration", name, elapsed);
+}
+
+fn main() {
+    let identifiers = "some_identifier another_one CamelCase ".repeat(1000);
+    let code = include_str!("../src/lib.rs");
+    let mut markup = String::new();
+    for _ in 0..1000 {
+        let _ = write!(markup, "<a href=\"x\">Tom & Jerry's</a>");
+    }
+
+    for (input_name, input) in [
+        ("identifiers", &*identifiers),
+        ("code", code),
+        ("markup", &*markup),
+    ] {
+        measure(
+            &format!("{} bytewise", input_name),
+            input,
+            write_escaped_bytewise,
+        );
+        measure(
+            &format!("{} write_escaped", input_name),
+            input,
+            write_escaped::<String>,
+        );
+    }
+}
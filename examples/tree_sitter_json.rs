@@ -0,0 +1,22 @@
+//! Highlights a JSON code block by the tree-sitter JSON grammar.
+//! Run by `cargo run --example tree_sitter_json --features tree-sitter`.
+use cmark_syntax::tree_sitter::Grammar;
+use cmark_syntax::{SyntaxConfig, SyntaxPreprocessor};
+use pulldown_cmark::{html, Parser};
+
+fn main() {
+    let grammar = Grammar::new(
+        tree_sitter_json::LANGUAGE.into(),
+        "json",
+        tree_sitter_json::HIGHLIGHTS_QUERY,
+    )
+    .expect("the bundled query is valid");
+    let config = SyntaxConfig::new().with_grammar("json", grammar);
+
+    let markdown = "```json\n{\"name\": \"cmark-syntax\", \"stars\": 42, \"ok\": true}\n```";
+    let processed = SyntaxPreprocessor::with_config(Parser::new(markdown), config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    println!("{}", rendered);
+}
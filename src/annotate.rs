@@ -0,0 +1,197 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{Kind, Markup};
+
+/// Words marking comments, such as `TODO`.
+const MARKERS: &[&str] = &["TODO", "FIXME", "XXX", "HACK", "NOTE"];
+
+/// All of the kinds, to tell whether another one shares the markup of a kind.
+const KINDS: [Kind; Kind::COUNT] = [
+    Kind::None,
+    Kind::Glyph,
+    Kind::Literal,
+    Kind::Identifier,
+    Kind::SpecialIdentifier,
+    Kind::StrongIdentifier,
+    Kind::Keyword,
+    Kind::Comment,
+    Kind::Number,
+    Kind::String,
+    Kind::Function,
+    Kind::Type,
+    Kind::Operator,
+    Kind::Punctuation,
+    Kind::Inserted,
+    Kind::Deleted,
+    Kind::Marker,
+];
+
+/// Markup added into the text of highlighted comments and strings.
+pub(crate) struct Annotations<'m> {
+    pub(crate) markup: &'m Markup,
+    /// Mark the words of `MARKERS` in comments.
+    pub(crate) markers: bool,
+    /// Turn URLs in comments and strings into links.
+    pub(crate) links: bool,
+}
+
+impl Annotations<'_> {
+    /// Whether there is nothing to annotate.
+    pub(crate) fn is_empty(&self) -> bool {
+        !self.markers && !self.links
+    }
+
+    /// Copy the highlighted `html` into `buf`, annotating the text
+    /// of its comments and strings.
+    pub(crate) fn annotate(&self, html: &str, buf: &mut String) {
+        let kinds: &[Kind] = match self.links {
+            true => &[Kind::Comment, Kind::String],
+            false => &[Kind::Comment],
+        };
+        annotate_runs(html, self.markup, kinds, buf, |kind, text, buf| {
+            let mut last = 0;
+            if self.links {
+                for url in urls(text) {
+                    self.mark(kind, &text[last..url.start], buf);
+                    // The URL is escaped already, for the attribute as well
+                    buf.push_str("<a href=\"");
+                    buf.push_str(&text[url.clone()]);
+                    buf.push_str("\">");
+                    buf.push_str(&text[url.clone()]);
+                    buf.push_str("</a>");
+                    last = url.end;
+                }
+            }
+            self.mark(kind, &text[last..], buf);
+        });
+    }
+
+    /// Copy the escaped `text` of a token of `kind` into `buf`,
+    /// wrapping the markers in comments into the markup of `Kind::Marker`.
+    fn mark(&self, kind: Kind, text: &str, buf: &mut String) {
+        if !self.markers || kind != Kind::Comment {
+            return buf.push_str(text);
+        }
+        let mut last = 0;
+        for (start, word) in words(text) {
+            if MARKERS.contains(&word) {
+                buf.push_str(&text[last..start]);
+                // Writing into a `String` never fails
+                let _ = self.markup.open(Kind::Marker, buf);
+                buf.push_str(word);
+                let _ = self.markup.close(Kind::Marker, buf);
+                last = start + word.len();
+            }
+        }
+        buf.push_str(&text[last..]);
+    }
+}
+
+/// Copy the highlighted `html` into `buf`, passing the text of the tokens
+/// of `kinds` through `annotate` instead.
+///
+/// The tokens of a kind opened by the same markup as another kind cannot be
+/// told apart, such as strings, which `TagMap::TAGS` places among `span` tags
+/// like numbers and literals, so they are copied as they are.
+fn annotate_runs(
+    html: &str,
+    markup: &Markup,
+    kinds: &[Kind],
+    buf: &mut String,
+    mut annotate: impl FnMut(Kind, &str, &mut String),
+) {
+    let markup_of = |kind| {
+        let mut open = String::new();
+        // Writing into a `String` never fails
+        let _ = markup.open(kind, &mut open);
+        open
+    };
+    let mut opens: Vec<_> = kinds
+        .iter()
+        .filter_map(|&kind| {
+            let open = markup_of(kind);
+            let shared = KINDS
+                .iter()
+                .any(|&other| other != kind && markup_of(other) == open);
+            let next = html.find(&open);
+            (!open.is_empty() && !shared).then_some((kind, open, next))
+        })
+        .collect();
+
+    let mut last = 0;
+    // The next run of any of the kinds, keeping the positions found before
+    while let Some((kind, start)) = opens
+        .iter()
+        .filter_map(|(kind, open, next)| next.map(|next| (*kind, next + open.len())))
+        .min_by_key(|&(_, start)| start)
+    {
+        buf.push_str(&html[last..start]);
+        // The text is escaped, so the run lasts until the next tag
+        let end = html[start..]
+            .find('<')
+            .map_or(html.len(), |end| start + end);
+        annotate(kind, &html[start..end], buf);
+        last = end;
+
+        for (_, open, next) in &mut opens {
+            if next.is_some_and(|next| next < end) {
+                *next = html[end..].find(&**open).map(|next| end + next);
+            }
+        }
+    }
+    buf.push_str(&html[last..]);
+}
+
+/// The words of `text` made of ASCII alphanumeric characters and underscores,
+/// together with their starting positions.
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_word = |(_, b): &(usize, u8)| b.is_ascii_alphanumeric() || *b == b'_';
+    let mut bytes = text.bytes().enumerate().peekable();
+    core::iter::from_fn(move || {
+        let (start, _) = bytes.find(is_word)?;
+        let mut end = start + 1;
+        while let Some((i, _)) = bytes.next_if(is_word) {
+            end = i + 1;
+        }
+        Some((start, &text[start..end]))
+    })
+}
+
+/// The ranges of the `http://` and `https://` URLs in the escaped `text`.
+fn urls(text: &str) -> impl Iterator<Item = core::ops::Range<usize>> + '_ {
+    let mut from = 0;
+    core::iter::from_fn(move || loop {
+        let start = from + text[from..].find("http")?;
+        let rest = &text[start..];
+        let scheme = ["http://", "https://"]
+            .into_iter()
+            .find(|scheme| rest.starts_with(scheme));
+        let Some(scheme) = scheme else {
+            from = start + "http".len();
+            continue;
+        };
+
+        // URLs end by whitespace or by escaped quotes and angle brackets
+        let mut end = rest.find(|c: char| c.is_whitespace()).unwrap_or(rest.len());
+        for delimiter in ["&lt;", "&gt;", "&quot;", "&#39;"] {
+            if let Some(i) = rest[..end].find(delimiter) {
+                end = i;
+            }
+        }
+        // Without trailing punctuation or an unbalanced parenthesis
+        let url = rest[..end].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        let url = match url.ends_with(')') && !url.contains('(') {
+            true => &url[..url.len() - 1],
+            false => url,
+        };
+
+        from = start + end.max(scheme.len());
+        if url.len() > scheme.len() {
+            return Some(start..start + url.len());
+        }
+    })
+}
@@ -0,0 +1,117 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{tokens, Highlight, Kind};
+use core::fmt;
+use logos::Logos;
+
+/// Mapping of the kinds of tokens to the parameters of the SGR escape
+/// sequences they are styled with, such as `1;34` for bold blue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scheme([Option<&'static str>; Kind::COUNT]);
+
+impl Scheme {
+    /// The scheme used by `highlight` by default.
+    pub const DEFAULT: Scheme = {
+        let mut styles = [None; Kind::COUNT];
+
+        styles[Kind::Glyph as usize] = Some("36");
+        styles[Kind::Literal as usize] = Some("35");
+        styles[Kind::SpecialIdentifier as usize] = Some("33");
+        styles[Kind::StrongIdentifier as usize] = Some("1");
+        styles[Kind::Keyword as usize] = Some("1");
+        styles[Kind::Comment as usize] = Some("2");
+        styles[Kind::Number as usize] = Some("35");
+        styles[Kind::String as usize] = Some("32");
+        styles[Kind::Function as usize] = Some("34");
+        styles[Kind::Type as usize] = Some("36");
+        styles[Kind::Inserted as usize] = Some("32");
+        styles[Kind::Deleted as usize] = Some("31");
+        styles[Kind::Marker as usize] = Some("1;33");
+
+        Scheme(styles)
+    };
+
+    /// Style tokens of `kind` by `style`, or leave them unstyled if `None`.
+    pub const fn with(mut self, kind: Kind, style: Option<&'static str>) -> Self {
+        self.0[kind as usize] = style;
+        self
+    }
+
+    /// The style `kind` maps to.
+    #[inline]
+    pub fn get(&self, kind: Kind) -> Option<&'static str> {
+        self.0[kind as usize]
+    }
+}
+
+impl Default for Scheme {
+    fn default() -> Self {
+        Scheme::DEFAULT
+    }
+}
+
+/// Highlight the code in `source` with ANSI escape sequences,
+/// placing the output into `buf`.
+#[inline]
+pub fn highlight<Token>(source: &str, buf: &mut (impl fmt::Write + ?Sized)) -> fmt::Result
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    highlight_with::<Token>(source, buf, &Scheme::DEFAULT)
+}
+
+/// Highlight the code in `source` with ANSI escape sequences styled by
+/// `scheme`, placing the output into `buf`.
+pub fn highlight_with<Token>(
+    source: &str,
+    buf: &mut (impl fmt::Write + ?Sized),
+    scheme: &Scheme,
+) -> fmt::Result
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    let mut tokens = tokens::<Token>(source);
+    let mut open = None;
+    let mut last = 0usize;
+
+    while let Some((kind, span)) = tokens.next_token() {
+        let style = scheme.get(kind);
+
+        if open != style {
+            // Reset previous style
+            if open.is_some() {
+                buf.write_str("\x1b[0m")?;
+            }
+
+            // Include trivia
+            buf.write_str(&source[last..span.start])?;
+
+            if let Some(style) = style {
+                write!(buf, "\x1b[{}m", style)?;
+            }
+
+            open = style;
+
+            buf.write_str(&source[span.clone()])?;
+        } else {
+            // Include trivia
+            buf.write_str(&source[last..span.end])?;
+        }
+
+        last = span.end;
+    }
+
+    // Include tail trivia
+    buf.write_str(&source[last..])?;
+
+    if open.is_some() {
+        buf.write_str("\x1b[0m")?;
+    }
+    Ok(())
+}
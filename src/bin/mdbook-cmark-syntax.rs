@@ -0,0 +1,32 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+//! An mdBook preprocessor highlighting code blocks by cmark-syntax.
+use cmark_syntax::mdbook;
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("supports") => match args.next() {
+            Some(renderer) if mdbook::supports(&renderer) => ExitCode::SUCCESS,
+            _ => ExitCode::FAILURE,
+        },
+        Some(arg) => {
+            eprintln!("unknown argument {:?}", arg);
+            ExitCode::FAILURE
+        }
+        None => match mdbook::run(io::stdin().lock(), io::stdout().lock()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("failed to preprocess the book: {}", e);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,294 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{HighlightFn, Language, Markup};
+#[cfg(feature = "latex2mathml")]
+use latex2mathml::DisplayStyle;
+use std::collections::BTreeMap;
+#[cfg(feature = "tree-sitter")]
+use {crate::tree_sitter::Grammar, std::sync::Arc};
+
+/// Configuration of a `SyntaxPreprocessor`.
+#[derive(Clone, Debug)]
+pub struct SyntaxConfig {
+    pub(crate) class_prefix: String,
+    pub(crate) markup: Markup,
+    pub(crate) wrapper: (String, String),
+    pub(crate) line_numbers: bool,
+    pub(crate) copy_button: bool,
+    pub(crate) inline_code: bool,
+    pub(crate) hidden_lines: bool,
+    pub(crate) comment_markers: bool,
+    pub(crate) links: bool,
+    pub(crate) normalize_newlines: bool,
+    pub(crate) tabs: Tabs,
+    pub(crate) max_size: Option<usize>,
+    pub(crate) languages: BTreeMap<String, HighlightFn>,
+    pub(crate) aliases: BTreeMap<String, String>,
+    #[cfg(feature = "tree-sitter")]
+    pub(crate) grammars: BTreeMap<String, Arc<Grammar>>,
+    #[cfg(feature = "latex2mathml")]
+    pub(crate) math: bool,
+    #[cfg(feature = "latex2mathml")]
+    pub(crate) math_languages: BTreeMap<String, DisplayStyle>,
+}
+
+/// The way tabs in code blocks are expanded into spaces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tabs {
+    /// Tabs are kept as they are.
+    #[default]
+    Keep,
+    /// Tabs in the indentation of lines are expanded to the given width,
+    /// usually 4.
+    Leading(usize),
+    /// All tabs are expanded to the next multiple of the given width
+    /// of columns.
+    All(usize),
+}
+
+impl Default for SyntaxConfig {
+    fn default() -> Self {
+        Self {
+            class_prefix: "language-".into(),
+            markup: Markup::default(),
+            wrapper: ("<pre><code>".into(), "</code></pre>".into()),
+            line_numbers: false,
+            copy_button: false,
+            inline_code: false,
+            hidden_lines: false,
+            comment_markers: false,
+            links: false,
+            normalize_newlines: true,
+            tabs: Tabs::Keep,
+            max_size: None,
+            languages: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            #[cfg(feature = "tree-sitter")]
+            grammars: BTreeMap::new(),
+            #[cfg(feature = "latex2mathml")]
+            math: true,
+            #[cfg(feature = "latex2mathml")]
+            math_languages: [
+                ("math".into(), DisplayStyle::Inline),
+                ("mathblock".into(), DisplayStyle::Block),
+            ]
+            .into(),
+        }
+    }
+}
+
+impl SyntaxConfig {
+    /// Create the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the prefix of the language class of the code element,
+    /// `language-` by default.
+    pub fn with_class_prefix(mut self, prefix: &str) -> Self {
+        self.class_prefix = prefix.into();
+        self
+    }
+
+    /// Set the way highlighted tokens are marked up,
+    /// with `TagMap::CLASSES` classes by default.
+    pub fn with_markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Set the markup wrapped around the code of code blocks,
+    /// `<pre><code>` and `</code></pre>` by default.
+    ///
+    /// The class and other attributes of code blocks are added to the last
+    /// tag of `open`, such as to the `pre` element with `<pre>` and `</pre>`,
+    /// merging the classes into its `class="..."` attribute if it has one.
+    /// They are left out if `open` does not end with a tag.
+    pub fn with_wrapper(mut self, open: &str, close: &str) -> Self {
+        self.wrapper = (open.into(), close.into());
+        self
+    }
+
+    /// Wrap each line of the highlighted code into a `span` of the `line`
+    /// class with its number in the `data-line` attribute, off by default.
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Wrap code blocks into a `div` of the `code-block` class together with
+    /// a `button` of the `copy-code` class and the `data-clipboard` attribute,
+    /// off by default.
+    ///
+    /// Copying has to be wired up by a script, which can read the code to be
+    /// copied from the text content of the `code` element.
+    pub fn with_copy_button(mut self, copy_button: bool) -> Self {
+        self.copy_button = copy_button;
+        self
+    }
+
+    /// Highlight inline code starting with a `#!lang ` hint, such as
+    /// `` `#!rust let x = 1` ``, as code of the language `lang`, off by default.
+    ///
+    /// The hint is removed and the code is put into a `code` element with the
+    /// language class. Inline code without a hint is left as it is.
+    pub fn with_inline_code(mut self, inline_code: bool) -> Self {
+        self.inline_code = inline_code;
+        self
+    }
+
+    /// Remove the lines hidden in Rust documentation from Rust code blocks,
+    /// off by default.
+    ///
+    /// As in rustdoc, lines starting with `# ` or consisting of `#` alone
+    /// are hidden, and a leading `##` is replaced by `#`.
+    pub fn with_hidden_lines(mut self, hidden_lines: bool) -> Self {
+        self.hidden_lines = hidden_lines;
+        self
+    }
+
+    /// Wrap the words `TODO`, `FIXME`, `XXX`, `HACK` and `NOTE` in comments
+    /// into the markup of `Kind::Marker`, off by default.
+    pub fn with_comment_markers(mut self, comment_markers: bool) -> Self {
+        self.comment_markers = comment_markers;
+        self
+    }
+
+    /// Turn `http://` and `https://` URLs in comments and strings into links,
+    /// off by default.
+    ///
+    /// With `Markup::Tags`, only the kinds whose tag no other kind shares are
+    /// linked, which leaves out strings with `TagMap::TAGS`.
+    pub fn with_links(mut self, links: bool) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Replace `\r\n` line endings in code blocks by `\n` before highlighting,
+    /// on by default.
+    ///
+    /// The `highlight` and `tokens` functions always work on the code as given,
+    /// so that the spans of tokens refer to it.
+    pub fn with_normalized_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.normalize_newlines = normalize_newlines;
+        self
+    }
+
+    /// Expand tabs in code blocks into spaces before highlighting,
+    /// with `Tabs::Keep` keeping them by default.
+    pub fn with_tabs(mut self, tabs: Tabs) -> Self {
+        self.tabs = tabs;
+        self
+    }
+
+    /// Leave code longer than `max_size` bytes unhighlighted, only escaping it,
+    /// or highlight code of any size if `None` as by default.
+    ///
+    /// This bounds the work spent on code blocks of untrusted documents.
+    pub fn with_max_size(mut self, max_size: Option<usize>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Highlight code blocks of language `name` by `highlight`,
+    /// in preference to the built-in languages.
+    pub fn with_language(mut self, name: &str, highlight: HighlightFn) -> Self {
+        self.register_language(name, highlight);
+        self
+    }
+
+    /// Highlight code blocks of language `name` by `highlight`,
+    /// in preference to the built-in languages.
+    ///
+    /// Language names are matched case-insensitively, and `name` may also be
+    /// an alias of a built-in language, such as `py`, to take only its place.
+    pub fn register_language(&mut self, name: &str, highlight: HighlightFn) {
+        self.languages.insert(name.to_ascii_lowercase(), highlight);
+    }
+
+    /// Highlight code blocks of language `name` by the tree-sitter `grammar`,
+    /// in preference to the built-in languages.
+    #[cfg(feature = "tree-sitter")]
+    pub fn with_grammar(mut self, name: &str, grammar: Grammar) -> Self {
+        self.register_grammar(name, grammar);
+        self
+    }
+
+    /// Highlight code blocks of language `name` by the tree-sitter `grammar`,
+    /// in preference to the built-in languages.
+    ///
+    /// Language names are matched case-insensitively.
+    #[cfg(feature = "tree-sitter")]
+    pub fn register_grammar(&mut self, name: &str, grammar: Grammar) {
+        self.grammars
+            .insert(name.to_ascii_lowercase(), Arc::new(grammar));
+    }
+
+    /// Highlight code blocks of language `from` as if they were of language `to`.
+    pub fn with_alias(mut self, from: &str, to: &str) -> Self {
+        self.add_alias(from, to);
+        self
+    }
+
+    /// Highlight code blocks of language `from` as if they were of language `to`.
+    ///
+    /// The aliases of the built-in languages, such as `rs` for `rust`,
+    /// are known by `Language::from_name` without adding them.
+    pub fn add_alias(&mut self, from: &str, to: &str) {
+        self.aliases
+            .insert(from.to_ascii_lowercase(), to.to_ascii_lowercase());
+    }
+
+    /// Render formulas and math code blocks into MathML, on by default.
+    ///
+    /// When off, formulas are passed through unchanged and math code blocks
+    /// are treated as any other code blocks.
+    #[cfg(feature = "latex2mathml")]
+    pub fn with_math(mut self, math: bool) -> Self {
+        self.math = math;
+        self
+    }
+
+    /// Render code blocks of language `name` into MathML in inline mode,
+    /// as is done for `math` by default.
+    #[cfg(feature = "latex2mathml")]
+    pub fn with_inline_math_language(mut self, name: &str) -> Self {
+        self.math_languages
+            .insert(name.to_ascii_lowercase(), DisplayStyle::Inline);
+        self
+    }
+
+    /// Render code blocks of language `name` into MathML in block mode,
+    /// as is done for `mathblock` by default.
+    #[cfg(feature = "latex2mathml")]
+    pub fn with_block_math_language(mut self, name: &str) -> Self {
+        self.math_languages
+            .insert(name.to_ascii_lowercase(), DisplayStyle::Block);
+        self
+    }
+
+    /// The language `name` is an alias of, or `name` itself.
+    pub(crate) fn resolve<'n>(&'n self, name: &'n str) -> &'n str {
+        let name = self.aliases.get(name).map_or(name, String::as_str);
+        Language::from_name(name).map_or(name, |language| language.name())
+    }
+
+    /// The entry of `map` registered for language `name`, looked up by `name`
+    /// itself before the language it is an alias of, so that languages
+    /// registered as `py` or `bash` take precedence over the built-in ones.
+    pub(crate) fn registered<'c, T>(
+        &'c self,
+        map: &'c BTreeMap<String, T>,
+        name: &str,
+    ) -> Option<&'c T> {
+        let alias = self.aliases.get(name).map(String::as_str);
+        [Some(name), alias, Some(self.resolve(name))]
+            .into_iter()
+            .flatten()
+            .find_map(|name| map.get(name))
+    }
+}
@@ -0,0 +1,118 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+
+/// Snippets typical for code of the languages, and rare in the others.
+const MARKERS: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &[
+            "fn main(",
+            "let mut ",
+            "use std::",
+            "impl ",
+            "pub fn ",
+            "println!(",
+            "&mut ",
+            "#[derive(",
+            "::new(",
+        ],
+    ),
+    (
+        "c",
+        &[
+            "#include",
+            "int main(",
+            "printf(",
+            "#define ",
+            "malloc(",
+            "sizeof(",
+        ],
+    ),
+    (
+        "js",
+        &["function ", "console.log(", "===", "require(", "document."],
+    ),
+];
+
+/// Guess the language of `code` from its content.
+///
+/// The interpreter of a shebang line decides, otherwise the language is
+/// recognized by snippets typical for it, as `fn main` for Rust or `#include`
+/// for C, or its structure, as sections and keys for TOML. If the guess is
+/// uncertain, `None` is returned.
+pub fn detect_language(code: &str) -> Option<&str> {
+    if let Some(shebang) = code.strip_prefix("#!") {
+        return interpreter(shebang.lines().next().unwrap_or(""));
+    }
+    if is_toml(code) {
+        return Some("toml");
+    }
+
+    let mut best = None;
+    let mut best_score = 0;
+    let mut tie = false;
+    for &(lang, markers) in MARKERS {
+        let score = markers
+            .iter()
+            .filter(|marker| code.contains(*marker))
+            .count();
+        if score > best_score {
+            best = Some(lang);
+            best_score = score;
+            tie = false;
+        } else if score == best_score {
+            tie = true;
+        }
+    }
+
+    best.filter(|_| !tie)
+}
+
+/// The language of the interpreter in a `shebang` line.
+fn interpreter(shebang: &str) -> Option<&str> {
+    let mut words = shebang.split_whitespace();
+    let mut name = words.next()?.rsplit('/').next()?;
+    if name == "env" {
+        name = words.find(|word| !word.starts_with('-'))?;
+    }
+    // Drop versions, as in `python3`
+    let name = name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+
+    match name {
+        "sh" | "bash" | "dash" | "ksh" | "zsh" => Some("sh"),
+        "node" | "deno" | "bun" => Some("js"),
+        "" => None,
+        name => Some(name),
+    }
+}
+
+/// Whether all of the lines of `code` are TOML tables, keys or comments.
+fn is_toml(code: &str) -> bool {
+    let mut tables = 0;
+    for line in code.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            tables += 1;
+        } else if !line
+            .split_once('=')
+            .is_some_and(|(key, _)| is_toml_key(key.trim()))
+        {
+            return false;
+        }
+    }
+    tables > 0
+}
+
+/// Whether `key` is a bare or dotted TOML key.
+fn is_toml_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'))
+}
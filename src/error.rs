@@ -0,0 +1,27 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use pulldown_cmark::Event;
+use std::{error, fmt};
+
+/// An error encountered while preprocessing `pulldown_cmark` events.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyntaxError<'a> {
+    /// A code block contained an event other than text.
+    UnexpectedEvent(Event<'a>),
+}
+
+impl fmt::Display for SyntaxError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SyntaxError::UnexpectedEvent(event) => {
+                write!(f, "unexpected event in code block: {:?}", event)
+            }
+        }
+    }
+}
+
+impl error::Error for SyntaxError<'_> {}
@@ -0,0 +1,114 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{write_escaped, Kind, Markup};
+use std::fmt;
+use std::sync::OnceLock;
+use syntect::easy::ScopeRangeIterator;
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+/// The syntaxes bundled with syntect, loaded on first use.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn find(lang: &str) -> Option<&'static SyntaxReference> {
+    syntax_set().find_syntax_by_token(lang)
+}
+
+/// Whether syntect knows the language `lang`.
+pub(crate) fn supports(lang: &str) -> bool {
+    find(lang).is_some()
+}
+
+/// Highlight `code` of the language `lang` using syntect, placing the output
+/// into `buf`. Prefixes of the scopes are mapped to the closest kinds.
+pub(crate) fn highlight(
+    lang: &str,
+    code: &str,
+    buf: &mut (impl fmt::Write + ?Sized),
+    markup: &Markup,
+) -> fmt::Result {
+    let Some(syntax) = find(lang) else {
+        return write_escaped(buf, code);
+    };
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut open = Kind::None;
+
+    for line in LinesWithEndings::from(code) {
+        let Ok(ops) = state.parse_line(line, syntax_set()) else {
+            // Leave lines syntect fails on unhighlighted
+            markup.close(open, buf)?;
+            open = Kind::None;
+            write_escaped(buf, line)?;
+            continue;
+        };
+
+        for (range, op) in ScopeRangeIterator::new(&ops, line) {
+            if stack.apply(op).is_err() || range.is_empty() {
+                continue;
+            }
+
+            let kind = kind_of(stack.as_slice());
+            if open != kind {
+                markup.close(open, buf)?;
+                markup.open(kind, buf)?;
+                open = kind;
+            }
+            write_escaped(buf, &line[range])?;
+        }
+    }
+
+    markup.close(open, buf)
+}
+
+/// Prefixes of scope names and the kinds they map to, the first match deciding.
+const SCOPES: &[(&str, Kind)] = &[
+    ("comment", Kind::Comment),
+    ("string", Kind::String),
+    ("constant.numeric", Kind::Number),
+    ("constant", Kind::Literal),
+    ("keyword.operator", Kind::Operator),
+    ("keyword", Kind::Keyword),
+    ("storage", Kind::Keyword),
+    ("entity.name.function", Kind::Function),
+    ("support.function", Kind::Function),
+    ("variable.function", Kind::Function),
+    ("entity.name", Kind::Type),
+    ("support.type", Kind::Type),
+    ("support.class", Kind::Type),
+    ("variable.language", Kind::SpecialIdentifier),
+    ("variable", Kind::Identifier),
+    ("punctuation", Kind::Punctuation),
+    ("markup.inserted", Kind::Inserted),
+    ("markup.deleted", Kind::Deleted),
+];
+
+/// The kind of a token in the scopes `stack`, the innermost known one deciding.
+fn kind_of(stack: &[Scope]) -> Kind {
+    stack
+        .iter()
+        .rev()
+        .map(|scope| scope.build_string())
+        // Delimiters belong to what they delimit
+        .filter(|name| !has_prefix(name, "punctuation.definition"))
+        .find_map(|name| {
+            SCOPES
+                .iter()
+                .find(|(prefix, _)| has_prefix(&name, prefix))
+                .map(|&(_, kind)| kind)
+        })
+        .unwrap_or(Kind::None)
+}
+
+/// Whether the scope `name` is `prefix` or nested in it.
+fn has_prefix(name: &str, prefix: &str) -> bool {
+    name.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
@@ -0,0 +1,140 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use std::ops::RangeInclusive;
+
+/// Information parsed from the info string of a fenced code block.
+///
+/// The first whitespace-delimited word is the language, optionally followed
+/// by comma-separated flags such as in `rust,no_run`, which are additional
+/// classes. It can be followed by
+/// * a comma-separated list of lines and `a-b` ranges of lines in braces,
+///   such as `{1,3-5}`, which are to be highlighted,
+/// * attributes of the form `key="value"` or `key=value`,
+/// * other words, which are additional classes.
+///
+/// Pandoc-style attributes in braces, such as `{.rust .numberLines}`, are
+/// understood as well, the first class being the language if there is no
+/// other.
+#[derive(Debug, Default)]
+pub(crate) struct Info<'a> {
+    pub lang: &'a str,
+    pub lines: Vec<RangeInclusive<usize>>,
+    pub attributes: Vec<(&'a str, &'a str)>,
+    pub classes: Vec<&'a str>,
+}
+
+impl<'a> Info<'a> {
+    pub fn parse(info: &'a str) -> Self {
+        let info = info.trim();
+        let (lang, rest) = match info.find(|c: char| c.is_whitespace() || c == '{') {
+            Some(idx) => info.split_at(idx),
+            None => (info, ""),
+        };
+        let (lang, flags) = lang.split_once(',').unwrap_or((lang, ""));
+        let mut parsed = Info {
+            lang,
+            classes: flags.split(',').filter(|flag| !flag.is_empty()).collect(),
+            ..Info::default()
+        };
+
+        for part in Parts(rest) {
+            if let Some(group) = part.strip_prefix('{') {
+                let group = group.strip_suffix('}').unwrap_or(group);
+                match parse_lines(group) {
+                    Some(lines) => parsed.lines.extend(lines),
+                    None => Parts(group).for_each(|part| parsed.add_pandoc(part)),
+                }
+            } else if let Some((key, value)) = part.split_once('=') {
+                parsed.attributes.push((key, unquote(value)));
+            } else {
+                parsed.classes.push(part);
+            }
+        }
+
+        parsed
+    }
+
+    /// Add a part of Pandoc-style attributes.
+    fn add_pandoc(&mut self, part: &'a str) {
+        if let Some(class) = part.strip_prefix('.') {
+            if self.lang.is_empty() {
+                self.lang = class;
+            } else {
+                self.classes.push(class);
+            }
+        } else if let Some((key, value)) = part.split_once('=') {
+            self.attributes.push((key, unquote(value)));
+        }
+    }
+
+    /// The value of the attribute `key`.
+    pub fn attribute(&self, key: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Iterator over the whitespace-delimited parts of an info string,
+/// keeping quoted strings and brace groups whole.
+struct Parts<'a>(&'a str);
+
+impl<'a> Iterator for Parts<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.0.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut quote = None;
+        let mut end = rest.len();
+        for (idx, c) in rest.char_indices() {
+            match (quote, c) {
+                (Some(q), _) if q == c => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '{') if idx == 0 => quote = Some('}'),
+                (None, c) if c.is_whitespace() => {
+                    end = idx;
+                    break;
+                }
+                (None, '{') => {
+                    end = idx;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let (part, rest) = rest.split_at(end);
+        self.0 = rest;
+        Some(part)
+    }
+}
+
+/// Strip the quotes around `value`, if any.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote) {
+            return inner.strip_suffix(quote).unwrap_or(inner);
+        }
+    }
+    value
+}
+
+/// Parse a comma-separated list of lines and ranges of lines.
+fn parse_lines(spec: &str) -> Option<Vec<RangeInclusive<usize>>> {
+    spec.split(',')
+        .map(|item| {
+            let (start, end) = item.split_once('-').unwrap_or((item, item));
+            Some(start.trim().parse().ok()?..=end.trim().parse().ok()?)
+        })
+        .collect()
+}
@@ -4,14 +4,710 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+// Without any languages enabled, `Language` has no variants to use the rest
+#![cfg_attr(
+    not(any(
+        feature = "lang-asm",
+        feature = "lang-c",
+        feature = "lang-clojure",
+        feature = "lang-cpp",
+        feature = "lang-crystal",
+        feature = "lang-css",
+        feature = "lang-diff",
+        feature = "lang-dockerfile",
+        feature = "lang-elixir",
+        feature = "lang-fsharp",
+        feature = "lang-glsl",
+        feature = "lang-go",
+        feature = "lang-graphql",
+        feature = "lang-groovy",
+        feature = "lang-haskell",
+        feature = "lang-html",
+        feature = "lang-ini",
+        feature = "lang-java",
+        feature = "lang-js",
+        feature = "lang-json",
+        feature = "lang-julia",
+        feature = "lang-kotlin",
+        feature = "lang-lua",
+        feature = "lang-makefile",
+        feature = "lang-objc",
+        feature = "lang-ocaml",
+        feature = "lang-perl",
+        feature = "lang-php",
+        feature = "lang-powershell",
+        feature = "lang-protobuf",
+        feature = "lang-python",
+        feature = "lang-r",
+        feature = "lang-ruby",
+        feature = "lang-rust",
+        feature = "lang-scala",
+        feature = "lang-scheme",
+        feature = "lang-scss",
+        feature = "lang-sh",
+        feature = "lang-solidity",
+        feature = "lang-sql",
+        feature = "lang-swift",
+        feature = "lang-toml",
+        feature = "lang-ts",
+        feature = "lang-wat",
+        feature = "lang-xml",
+        feature = "lang-yaml",
+        feature = "lang-zig"
+    )),
+    allow(unused, unreachable_code)
+)]
+use crate::{highlight_with, Markup};
+use core::fmt;
+
+#[cfg(feature = "lang-asm")]
+mod asm;
+#[cfg(feature = "lang-c")]
 mod c;
+#[cfg(feature = "lang-clojure")]
+mod clojure;
+#[cfg(feature = "lang-cpp")]
+mod cpp;
+#[cfg(feature = "lang-crystal")]
+mod crystal;
+#[cfg(feature = "lang-css")]
+mod css;
+#[cfg(feature = "lang-diff")]
+mod diff;
+#[cfg(feature = "lang-dockerfile")]
+mod dockerfile;
+#[cfg(feature = "lang-elixir")]
+mod elixir;
+#[cfg(feature = "lang-fsharp")]
+mod fsharp;
+#[cfg(feature = "lang-glsl")]
+mod glsl;
+#[cfg(feature = "lang-go")]
+mod go;
+#[cfg(feature = "lang-graphql")]
+mod graphql;
+#[cfg(feature = "lang-groovy")]
+mod groovy;
+#[cfg(feature = "lang-haskell")]
+mod haskell;
+#[cfg(feature = "lang-html")]
+mod html;
+#[cfg(feature = "lang-ini")]
+mod ini;
+#[cfg(feature = "lang-java")]
+mod java;
+#[cfg(feature = "lang-js")]
 mod javascript;
+#[cfg(feature = "lang-json")]
+mod json;
+#[cfg(feature = "lang-julia")]
+mod julia;
+#[cfg(feature = "lang-kotlin")]
+mod kotlin;
+#[cfg(feature = "lang-lua")]
+mod lua;
+#[cfg(feature = "lang-makefile")]
+mod makefile;
+#[cfg(feature = "lang-objc")]
+mod objc;
+#[cfg(feature = "lang-ocaml")]
+mod ocaml;
+#[cfg(feature = "lang-perl")]
+mod perl;
+#[cfg(feature = "lang-php")]
+mod php;
+#[cfg(feature = "lang-powershell")]
+mod powershell;
+#[cfg(feature = "lang-protobuf")]
+mod protobuf;
+#[cfg(feature = "lang-python")]
+mod python;
+#[cfg(feature = "lang-r")]
+mod r;
+#[cfg(feature = "lang-ruby")]
+mod ruby;
+#[cfg(feature = "lang-rust")]
 mod rust;
+#[cfg(feature = "lang-scala")]
+mod scala;
+#[cfg(feature = "lang-scheme")]
+mod scheme;
+#[cfg(feature = "lang-scss")]
+mod scss;
+#[cfg(feature = "lang-sh")]
 mod sh;
+#[cfg(feature = "lang-solidity")]
+mod solidity;
+#[cfg(feature = "lang-sql")]
+mod sql;
+#[cfg(feature = "lang-swift")]
+mod swift;
+#[cfg(feature = "lang-toml")]
 mod toml;
+#[cfg(feature = "lang-ts")]
+mod typescript;
+#[cfg(feature = "lang-wat")]
+mod wat;
+#[cfg(feature = "lang-xml")]
+mod xml;
+#[cfg(feature = "lang-yaml")]
+mod yaml;
+#[cfg(feature = "lang-zig")]
+mod zig;
 
+#[cfg(feature = "lang-asm")]
+pub use asm::Asm;
+#[cfg(feature = "lang-c")]
 pub use c::C;
+#[cfg(feature = "lang-clojure")]
+pub use clojure::Clojure;
+#[cfg(feature = "lang-cpp")]
+pub use cpp::Cpp;
+#[cfg(feature = "lang-crystal")]
+pub use crystal::Crystal;
+#[cfg(feature = "lang-css")]
+pub use css::Css;
+#[cfg(feature = "lang-diff")]
+pub use diff::Diff;
+#[cfg(feature = "lang-dockerfile")]
+pub use dockerfile::Dockerfile;
+#[cfg(feature = "lang-elixir")]
+pub use elixir::Elixir;
+#[cfg(feature = "lang-fsharp")]
+pub use fsharp::FSharp;
+#[cfg(feature = "lang-glsl")]
+pub use glsl::Glsl;
+#[cfg(feature = "lang-go")]
+pub use go::Go;
+#[cfg(feature = "lang-graphql")]
+pub use graphql::GraphQl;
+#[cfg(feature = "lang-groovy")]
+pub use groovy::Groovy;
+#[cfg(feature = "lang-haskell")]
+pub use haskell::Haskell;
+#[cfg(feature = "lang-html")]
+pub use html::Html;
+#[cfg(feature = "lang-ini")]
+pub use ini::Ini;
+#[cfg(feature = "lang-java")]
+pub use java::Java;
+#[cfg(feature = "lang-js")]
 pub use javascript::JavaScript;
+#[cfg(feature = "lang-json")]
+pub use json::Json;
+#[cfg(feature = "lang-julia")]
+pub use julia::Julia;
+#[cfg(feature = "lang-kotlin")]
+pub use kotlin::Kotlin;
+#[cfg(feature = "lang-lua")]
+pub use lua::Lua;
+#[cfg(feature = "lang-makefile")]
+pub use makefile::Makefile;
+#[cfg(feature = "lang-objc")]
+pub use objc::ObjectiveC;
+#[cfg(feature = "lang-ocaml")]
+pub use ocaml::Ocaml;
+#[cfg(feature = "lang-perl")]
+pub use perl::Perl;
+#[cfg(feature = "lang-php")]
+pub use php::Php;
+#[cfg(feature = "lang-powershell")]
+pub use powershell::PowerShell;
+#[cfg(feature = "lang-protobuf")]
+pub use protobuf::Protobuf;
+#[cfg(feature = "lang-python")]
+pub use python::Python;
+#[cfg(feature = "lang-r")]
+pub use r::R;
+#[cfg(feature = "lang-ruby")]
+pub use ruby::Ruby;
+#[cfg(feature = "lang-rust")]
 pub use rust::Rust;
+#[cfg(feature = "lang-scala")]
+pub use scala::Scala;
+#[cfg(feature = "lang-scheme")]
+pub use scheme::Scheme;
+#[cfg(feature = "lang-scss")]
+pub use scss::Scss;
+#[cfg(feature = "lang-sh")]
 pub use sh::Sh;
+#[cfg(feature = "lang-solidity")]
+pub use solidity::Solidity;
+#[cfg(feature = "lang-sql")]
+pub use sql::Sql;
+#[cfg(feature = "lang-swift")]
+pub use swift::Swift;
+#[cfg(feature = "lang-toml")]
 pub use toml::Toml;
+#[cfg(feature = "lang-ts")]
+pub use typescript::TypeScript;
+#[cfg(feature = "lang-wat")]
+pub use wat::Wat;
+#[cfg(feature = "lang-xml")]
+pub use xml::Xml;
+#[cfg(feature = "lang-yaml")]
+pub use yaml::Yaml;
+#[cfg(feature = "lang-zig")]
+pub use zig::Zig;
+
+/// A built-in language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// x86 assembly.
+    #[cfg(feature = "lang-asm")]
+    Asm,
+    /// C.
+    #[cfg(feature = "lang-c")]
+    C,
+    /// Clojure and EDN.
+    #[cfg(feature = "lang-clojure")]
+    Clojure,
+    /// C++.
+    #[cfg(feature = "lang-cpp")]
+    Cpp,
+    /// Crystal.
+    #[cfg(feature = "lang-crystal")]
+    Crystal,
+    /// CSS.
+    #[cfg(feature = "lang-css")]
+    Css,
+    /// Unified diffs.
+    #[cfg(feature = "lang-diff")]
+    Diff,
+    /// Dockerfiles.
+    #[cfg(feature = "lang-dockerfile")]
+    Dockerfile,
+    /// Elixir.
+    #[cfg(feature = "lang-elixir")]
+    Elixir,
+    /// F#.
+    #[cfg(feature = "lang-fsharp")]
+    FSharp,
+    /// GLSL shaders.
+    #[cfg(feature = "lang-glsl")]
+    Glsl,
+    /// Go.
+    #[cfg(feature = "lang-go")]
+    Go,
+    /// GraphQL.
+    #[cfg(feature = "lang-graphql")]
+    GraphQl,
+    /// Groovy and Gradle build scripts.
+    #[cfg(feature = "lang-groovy")]
+    Groovy,
+    /// Haskell.
+    #[cfg(feature = "lang-haskell")]
+    Haskell,
+    /// HTML.
+    #[cfg(feature = "lang-html")]
+    Html,
+    /// INI files and Java properties.
+    #[cfg(feature = "lang-ini")]
+    Ini,
+    /// Java.
+    #[cfg(feature = "lang-java")]
+    Java,
+    /// JavaScript.
+    #[cfg(feature = "lang-js")]
+    JavaScript,
+    /// JSON.
+    #[cfg(feature = "lang-json")]
+    Json,
+    /// Julia.
+    #[cfg(feature = "lang-julia")]
+    Julia,
+    /// Kotlin.
+    #[cfg(feature = "lang-kotlin")]
+    Kotlin,
+    /// Lua.
+    #[cfg(feature = "lang-lua")]
+    Lua,
+    /// Makefiles.
+    #[cfg(feature = "lang-makefile")]
+    Makefile,
+    /// Objective-C.
+    #[cfg(feature = "lang-objc")]
+    ObjectiveC,
+    /// OCaml.
+    #[cfg(feature = "lang-ocaml")]
+    Ocaml,
+    /// Perl.
+    #[cfg(feature = "lang-perl")]
+    Perl,
+    /// PHP.
+    #[cfg(feature = "lang-php")]
+    Php,
+    /// PowerShell.
+    #[cfg(feature = "lang-powershell")]
+    PowerShell,
+    /// Protocol Buffers.
+    #[cfg(feature = "lang-protobuf")]
+    Protobuf,
+    /// Python.
+    #[cfg(feature = "lang-python")]
+    Python,
+    /// R.
+    #[cfg(feature = "lang-r")]
+    R,
+    /// Ruby.
+    #[cfg(feature = "lang-ruby")]
+    Ruby,
+    /// Rust.
+    #[cfg(feature = "lang-rust")]
+    Rust,
+    /// Scala.
+    #[cfg(feature = "lang-scala")]
+    Scala,
+    /// Scheme and other Lisps.
+    #[cfg(feature = "lang-scheme")]
+    Scheme,
+    /// SCSS.
+    #[cfg(feature = "lang-scss")]
+    Scss,
+    /// sh shell.
+    #[cfg(feature = "lang-sh")]
+    Sh,
+    /// Solidity.
+    #[cfg(feature = "lang-solidity")]
+    Solidity,
+    /// SQL.
+    #[cfg(feature = "lang-sql")]
+    Sql,
+    /// Swift.
+    #[cfg(feature = "lang-swift")]
+    Swift,
+    /// TOML.
+    #[cfg(feature = "lang-toml")]
+    Toml,
+    /// TypeScript.
+    #[cfg(feature = "lang-ts")]
+    TypeScript,
+    /// The WebAssembly text format.
+    #[cfg(feature = "lang-wat")]
+    Wat,
+    /// XML.
+    #[cfg(feature = "lang-xml")]
+    Xml,
+    /// YAML.
+    #[cfg(feature = "lang-yaml")]
+    Yaml,
+    /// Zig.
+    #[cfg(feature = "lang-zig")]
+    Zig,
+}
+
+impl Language {
+    /// The language named `name` in lowercase, or by one of its aliases,
+    /// such as `rs` for `rust`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            #[cfg(feature = "lang-asm")]
+            "asm" | "nasm" | "gas" | "s" | "x86asm" => Language::Asm,
+            #[cfg(feature = "lang-c")]
+            "c" | "h" => Language::C,
+            #[cfg(feature = "lang-clojure")]
+            "clojure" | "clj" | "cljs" | "cljc" | "edn" => Language::Clojure,
+            #[cfg(feature = "lang-cpp")]
+            "cpp" | "c++" | "cxx" | "hpp" | "cc" => Language::Cpp,
+            #[cfg(feature = "lang-crystal")]
+            "crystal" | "cr" => Language::Crystal,
+            #[cfg(feature = "lang-css")]
+            "css" => Language::Css,
+            #[cfg(feature = "lang-diff")]
+            "diff" | "patch" => Language::Diff,
+            #[cfg(feature = "lang-dockerfile")]
+            "dockerfile" | "docker" | "containerfile" => Language::Dockerfile,
+            #[cfg(feature = "lang-elixir")]
+            "elixir" | "ex" | "exs" => Language::Elixir,
+            #[cfg(feature = "lang-fsharp")]
+            "fsharp" | "fs" => Language::FSharp,
+            #[cfg(feature = "lang-glsl")]
+            "glsl" | "vert" | "frag" => Language::Glsl,
+            #[cfg(feature = "lang-go")]
+            "go" | "golang" => Language::Go,
+            #[cfg(feature = "lang-graphql")]
+            "graphql" | "gql" => Language::GraphQl,
+            #[cfg(feature = "lang-groovy")]
+            "groovy" | "gradle" => Language::Groovy,
+            #[cfg(feature = "lang-haskell")]
+            "haskell" | "hs" => Language::Haskell,
+            #[cfg(feature = "lang-html")]
+            "html" | "htm" => Language::Html,
+            #[cfg(feature = "lang-ini")]
+            "ini" | "cfg" | "properties" => Language::Ini,
+            #[cfg(feature = "lang-java")]
+            "java" => Language::Java,
+            #[cfg(feature = "lang-js")]
+            "js" | "javascript" => Language::JavaScript,
+            #[cfg(feature = "lang-json")]
+            "json" => Language::Json,
+            #[cfg(feature = "lang-julia")]
+            "julia" | "jl" => Language::Julia,
+            #[cfg(feature = "lang-kotlin")]
+            "kotlin" | "kt" | "kts" => Language::Kotlin,
+            #[cfg(feature = "lang-lua")]
+            "lua" => Language::Lua,
+            #[cfg(feature = "lang-makefile")]
+            "makefile" | "make" | "mk" => Language::Makefile,
+            #[cfg(feature = "lang-objc")]
+            "objective-c" | "objc" | "m" => Language::ObjectiveC,
+            #[cfg(feature = "lang-ocaml")]
+            "ocaml" | "ml" => Language::Ocaml,
+            #[cfg(feature = "lang-perl")]
+            "perl" | "pl" | "pm" => Language::Perl,
+            #[cfg(feature = "lang-php")]
+            "php" => Language::Php,
+            #[cfg(feature = "lang-powershell")]
+            "powershell" | "ps1" | "pwsh" => Language::PowerShell,
+            #[cfg(feature = "lang-protobuf")]
+            "protobuf" | "proto" => Language::Protobuf,
+            #[cfg(feature = "lang-python")]
+            "python" | "py" | "py3" => Language::Python,
+            #[cfg(feature = "lang-r")]
+            "r" | "rscript" => Language::R,
+            #[cfg(feature = "lang-ruby")]
+            "ruby" | "rb" => Language::Ruby,
+            #[cfg(feature = "lang-rust")]
+            "rust" | "rs" => Language::Rust,
+            #[cfg(feature = "lang-scala")]
+            "scala" | "sc" => Language::Scala,
+            #[cfg(feature = "lang-scheme")]
+            "scheme" | "lisp" | "racket" | "el" => Language::Scheme,
+            #[cfg(feature = "lang-scss")]
+            "scss" | "sass" => Language::Scss,
+            #[cfg(feature = "lang-sh")]
+            "sh" | "shell" | "bash" => Language::Sh,
+            #[cfg(feature = "lang-solidity")]
+            "solidity" | "sol" => Language::Solidity,
+            #[cfg(feature = "lang-sql")]
+            "sql" => Language::Sql,
+            #[cfg(feature = "lang-swift")]
+            "swift" => Language::Swift,
+            #[cfg(feature = "lang-toml")]
+            "toml" => Language::Toml,
+            #[cfg(feature = "lang-ts")]
+            "ts" | "typescript" | "tsx" => Language::TypeScript,
+            #[cfg(feature = "lang-wat")]
+            "wat" | "wasm" => Language::Wat,
+            #[cfg(feature = "lang-xml")]
+            "xml" | "svg" | "xsd" | "xsl" | "plist" => Language::Xml,
+            #[cfg(feature = "lang-yaml")]
+            "yaml" | "yml" => Language::Yaml,
+            #[cfg(feature = "lang-zig")]
+            "zig" => Language::Zig,
+            _ => return None,
+        })
+    }
+
+    /// The name of the language, such as `rust`.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "lang-asm")]
+            Language::Asm => "asm",
+            #[cfg(feature = "lang-c")]
+            Language::C => "c",
+            #[cfg(feature = "lang-clojure")]
+            Language::Clojure => "clojure",
+            #[cfg(feature = "lang-cpp")]
+            Language::Cpp => "cpp",
+            #[cfg(feature = "lang-crystal")]
+            Language::Crystal => "crystal",
+            #[cfg(feature = "lang-css")]
+            Language::Css => "css",
+            #[cfg(feature = "lang-diff")]
+            Language::Diff => "diff",
+            #[cfg(feature = "lang-dockerfile")]
+            Language::Dockerfile => "dockerfile",
+            #[cfg(feature = "lang-elixir")]
+            Language::Elixir => "elixir",
+            #[cfg(feature = "lang-fsharp")]
+            Language::FSharp => "fsharp",
+            #[cfg(feature = "lang-glsl")]
+            Language::Glsl => "glsl",
+            #[cfg(feature = "lang-go")]
+            Language::Go => "go",
+            #[cfg(feature = "lang-graphql")]
+            Language::GraphQl => "graphql",
+            #[cfg(feature = "lang-groovy")]
+            Language::Groovy => "groovy",
+            #[cfg(feature = "lang-haskell")]
+            Language::Haskell => "haskell",
+            #[cfg(feature = "lang-html")]
+            Language::Html => "html",
+            #[cfg(feature = "lang-ini")]
+            Language::Ini => "ini",
+            #[cfg(feature = "lang-java")]
+            Language::Java => "java",
+            #[cfg(feature = "lang-js")]
+            Language::JavaScript => "js",
+            #[cfg(feature = "lang-json")]
+            Language::Json => "json",
+            #[cfg(feature = "lang-julia")]
+            Language::Julia => "julia",
+            #[cfg(feature = "lang-kotlin")]
+            Language::Kotlin => "kotlin",
+            #[cfg(feature = "lang-lua")]
+            Language::Lua => "lua",
+            #[cfg(feature = "lang-makefile")]
+            Language::Makefile => "makefile",
+            #[cfg(feature = "lang-objc")]
+            Language::ObjectiveC => "objective-c",
+            #[cfg(feature = "lang-ocaml")]
+            Language::Ocaml => "ocaml",
+            #[cfg(feature = "lang-perl")]
+            Language::Perl => "perl",
+            #[cfg(feature = "lang-php")]
+            Language::Php => "php",
+            #[cfg(feature = "lang-powershell")]
+            Language::PowerShell => "powershell",
+            #[cfg(feature = "lang-protobuf")]
+            Language::Protobuf => "protobuf",
+            #[cfg(feature = "lang-python")]
+            Language::Python => "python",
+            #[cfg(feature = "lang-r")]
+            Language::R => "r",
+            #[cfg(feature = "lang-ruby")]
+            Language::Ruby => "ruby",
+            #[cfg(feature = "lang-rust")]
+            Language::Rust => "rust",
+            #[cfg(feature = "lang-scala")]
+            Language::Scala => "scala",
+            #[cfg(feature = "lang-scheme")]
+            Language::Scheme => "scheme",
+            #[cfg(feature = "lang-scss")]
+            Language::Scss => "scss",
+            #[cfg(feature = "lang-sh")]
+            Language::Sh => "sh",
+            #[cfg(feature = "lang-solidity")]
+            Language::Solidity => "solidity",
+            #[cfg(feature = "lang-sql")]
+            Language::Sql => "sql",
+            #[cfg(feature = "lang-swift")]
+            Language::Swift => "swift",
+            #[cfg(feature = "lang-toml")]
+            Language::Toml => "toml",
+            #[cfg(feature = "lang-ts")]
+            Language::TypeScript => "ts",
+            #[cfg(feature = "lang-wat")]
+            Language::Wat => "wat",
+            #[cfg(feature = "lang-xml")]
+            Language::Xml => "xml",
+            #[cfg(feature = "lang-yaml")]
+            Language::Yaml => "yaml",
+            #[cfg(feature = "lang-zig")]
+            Language::Zig => "zig",
+        }
+    }
+
+    /// Highlight `code` of the language, writing the output into `buf`.
+    #[inline]
+    pub fn highlight(&self, code: &str, buf: &mut (impl fmt::Write + ?Sized)) -> fmt::Result {
+        self.highlight_with(code, buf, &Markup::default())
+    }
+
+    /// Highlight `code` of the language using `markup`, writing the output into `buf`.
+    pub fn highlight_with(
+        &self,
+        code: &str,
+        buf: &mut (impl fmt::Write + ?Sized),
+        markup: &Markup,
+    ) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "lang-asm")]
+            Language::Asm => highlight_with::<Asm>(code, buf, markup),
+            #[cfg(feature = "lang-c")]
+            Language::C => highlight_with::<C>(code, buf, markup),
+            #[cfg(feature = "lang-clojure")]
+            Language::Clojure => highlight_with::<Clojure>(code, buf, markup),
+            #[cfg(feature = "lang-cpp")]
+            Language::Cpp => highlight_with::<Cpp>(code, buf, markup),
+            #[cfg(feature = "lang-crystal")]
+            Language::Crystal => highlight_with::<Crystal>(code, buf, markup),
+            #[cfg(feature = "lang-css")]
+            Language::Css => highlight_with::<Css>(code, buf, markup),
+            #[cfg(feature = "lang-diff")]
+            Language::Diff => highlight_with::<Diff>(code, buf, markup),
+            #[cfg(feature = "lang-dockerfile")]
+            Language::Dockerfile => highlight_with::<Dockerfile>(code, buf, markup),
+            #[cfg(feature = "lang-elixir")]
+            Language::Elixir => highlight_with::<Elixir>(code, buf, markup),
+            #[cfg(feature = "lang-fsharp")]
+            Language::FSharp => highlight_with::<FSharp>(code, buf, markup),
+            #[cfg(feature = "lang-glsl")]
+            Language::Glsl => highlight_with::<Glsl>(code, buf, markup),
+            #[cfg(feature = "lang-go")]
+            Language::Go => highlight_with::<Go>(code, buf, markup),
+            #[cfg(feature = "lang-graphql")]
+            Language::GraphQl => highlight_with::<GraphQl>(code, buf, markup),
+            #[cfg(feature = "lang-groovy")]
+            Language::Groovy => highlight_with::<Groovy>(code, buf, markup),
+            #[cfg(feature = "lang-haskell")]
+            Language::Haskell => highlight_with::<Haskell>(code, buf, markup),
+            #[cfg(feature = "lang-html")]
+            Language::Html => highlight_with::<Html>(code, buf, markup),
+            #[cfg(feature = "lang-ini")]
+            Language::Ini => highlight_with::<Ini>(code, buf, markup),
+            #[cfg(feature = "lang-java")]
+            Language::Java => highlight_with::<Java>(code, buf, markup),
+            #[cfg(feature = "lang-js")]
+            Language::JavaScript => highlight_with::<JavaScript>(code, buf, markup),
+            #[cfg(feature = "lang-json")]
+            Language::Json => highlight_with::<Json>(code, buf, markup),
+            #[cfg(feature = "lang-julia")]
+            Language::Julia => highlight_with::<Julia>(code, buf, markup),
+            #[cfg(feature = "lang-kotlin")]
+            Language::Kotlin => highlight_with::<Kotlin>(code, buf, markup),
+            #[cfg(feature = "lang-lua")]
+            Language::Lua => highlight_with::<Lua>(code, buf, markup),
+            #[cfg(feature = "lang-makefile")]
+            Language::Makefile => highlight_with::<Makefile>(code, buf, markup),
+            #[cfg(feature = "lang-objc")]
+            Language::ObjectiveC => highlight_with::<ObjectiveC>(code, buf, markup),
+            #[cfg(feature = "lang-ocaml")]
+            Language::Ocaml => highlight_with::<Ocaml>(code, buf, markup),
+            #[cfg(feature = "lang-perl")]
+            Language::Perl => highlight_with::<Perl>(code, buf, markup),
+            #[cfg(feature = "lang-php")]
+            Language::Php => highlight_with::<Php>(code, buf, markup),
+            #[cfg(feature = "lang-powershell")]
+            Language::PowerShell => highlight_with::<PowerShell>(code, buf, markup),
+            #[cfg(feature = "lang-protobuf")]
+            Language::Protobuf => highlight_with::<Protobuf>(code, buf, markup),
+            #[cfg(feature = "lang-python")]
+            Language::Python => highlight_with::<Python>(code, buf, markup),
+            #[cfg(feature = "lang-r")]
+            Language::R => highlight_with::<R>(code, buf, markup),
+            #[cfg(feature = "lang-ruby")]
+            Language::Ruby => highlight_with::<Ruby>(code, buf, markup),
+            #[cfg(feature = "lang-rust")]
+            Language::Rust => highlight_with::<Rust>(code, buf, markup),
+            #[cfg(feature = "lang-scala")]
+            Language::Scala => highlight_with::<Scala>(code, buf, markup),
+            #[cfg(feature = "lang-scheme")]
+            Language::Scheme => highlight_with::<Scheme>(code, buf, markup),
+            #[cfg(feature = "lang-scss")]
+            Language::Scss => highlight_with::<Scss>(code, buf, markup),
+            #[cfg(feature = "lang-sh")]
+            Language::Sh => highlight_with::<Sh>(code, buf, markup),
+            #[cfg(feature = "lang-solidity")]
+            Language::Solidity => highlight_with::<Solidity>(code, buf, markup),
+            #[cfg(feature = "lang-sql")]
+            Language::Sql => highlight_with::<Sql>(code, buf, markup),
+            #[cfg(feature = "lang-swift")]
+            Language::Swift => highlight_with::<Swift>(code, buf, markup),
+            #[cfg(feature = "lang-toml")]
+            Language::Toml => highlight_with::<Toml>(code, buf, markup),
+            #[cfg(feature = "lang-ts")]
+            Language::TypeScript => highlight_with::<TypeScript>(code, buf, markup),
+            #[cfg(feature = "lang-wat")]
+            Language::Wat => highlight_with::<Wat>(code, buf, markup),
+            #[cfg(feature = "lang-xml")]
+            Language::Xml => highlight_with::<Xml>(code, buf, markup),
+            #[cfg(feature = "lang-yaml")]
+            Language::Yaml => highlight_with::<Yaml>(code, buf, markup),
+            #[cfg(feature = "lang-zig")]
+            Language::Zig => highlight_with::<Zig>(code, buf, markup),
+        }
+    }
+}
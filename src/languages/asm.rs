@@ -0,0 +1,88 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+/// x86 assembly in the AT&T syntax of the GNU assembler, such as
+/// `movq $0x10, %rax`.
+///
+/// Registers without `%`, size specifiers such as `dword ptr` and `;` comments
+/// are also recognized for the Intel syntax of NASM, so that `;` is always
+/// taken as starting a comment instead of separating statements.
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Asm {
+    #[regex("[a-zA-Z_$@?][a-zA-Z0-9_.$@?]*")]
+    Identifier,
+
+    #[regex("[a-zA-Z_.$@?][a-zA-Z0-9_.$@?]*:")]
+    Label,
+
+    #[regex("\\.[a-zA-Z_][a-zA-Z0-9_.$]*")]
+    Directive,
+
+    #[regex("%[a-z][a-z0-9]*")]
+    #[regex(
+        "[re]?(ax|bx|cx|dx|si|di|sp|bp|ip)|[abcd][lh]|sil|dil|spl|bpl",
+        priority = 8
+    )]
+    #[regex(
+        "r([89]|1[0-5])[dwb]?|[xyz]mm([0-9]|[12][0-9]|3[01])|k[0-7]",
+        priority = 8
+    )]
+    #[regex("[cdefgs]s|cr[0-8]|dr[0-7]|st", priority = 8)]
+    Register,
+
+    #[regex("(?i)byte|word|dword|qword|tword|oword|yword|zword|ptr", priority = 8)]
+    Size,
+
+    #[regex("\\$[a-zA-Z0-9_.-]+")]
+    Immediate,
+
+    #[regex("[0-9]+")]
+    #[regex("0[xX][0-9a-fA-F]+")]
+    #[regex("0[bB][01]+")]
+    #[regex("[0-9][0-9a-fA-F]*[hH]")]
+    Number,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    String,
+
+    #[regex("\\+|-|\\*|/|<<|>>|&|\\||\\^|~|=")]
+    Glyph,
+
+    #[regex(",|:|\\(|\\)|\\[|\\]")]
+    Punctuation,
+
+    #[token("\n")]
+    Newline,
+
+    #[regex("(#|;|//)[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Asm {
+    const LANG: &'static str = "asm";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Asm::*;
+
+        match tokens {
+            // Mnemonics and directives start statements, possibly after a label
+            [None | Newline | Label, Identifier] => Kind::Keyword,
+            [None | Newline | Label, Directive] => Kind::Glyph,
+            [_, Identifier | Directive] => Kind::Identifier,
+            [_, Label] => Kind::Function,
+            [_, Register] => Kind::SpecialIdentifier,
+            [_, Size] => Kind::Type,
+            [_, Immediate | Number] => Kind::Number,
+            [_, String] => Kind::String,
+            [_, Glyph] => Kind::Operator,
+            [_, Punctuation] => Kind::Punctuation,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
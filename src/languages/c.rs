@@ -6,12 +6,14 @@ pub enum C {
     #[regex("[a-zA-Z_$][a-zA-Z0-9_]*")]
     Identifier,
 
-    #[regex("\"([^\"\\\\]|\\\\[.\n])*\"")]
-    #[regex("'([^']|\\\\')'")]
+    #[regex("\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\]|\\\\.)'")]
+    String,
+
     #[regex("[0-9][0-9]*")]
     #[regex("0[xX][0-9a-fA-F]+")]
     #[regex("0[bB][01]+")]
-    Literal,
+    Number,
 
     #[regex(r#"\?|:|!|\^|-|\+|\*|&|/|<|>|="#, priority = 3)]
     Glyph,
@@ -37,7 +39,7 @@ pub enum C {
     #[regex("auto|const|extern|inline|register|static|volatile")]
     Qualifier,
 
-    #[regex("bool|char|complex|double|float|imaginary|long")]
+    #[regex("bool|char|complex|double|float|imaginary|int|long")]
     #[regex("short|signed|unsigned|void")]
     Type,
 
@@ -55,16 +57,17 @@ impl Highlight for C {
     const LANG: &'static str = "c";
     const START: Self = Self::None;
 
-    fn kind(tokens: &[Self; 2]) -> Kind {
+    fn kind(tokens: &[Self]) -> Kind {
         use C::*;
 
         match tokens {
-            [KeywordCtx, Identifier] | [GlyphCtx, Identifier] | [_, Type] => {
-                Kind::SpecialIdentifier
-            }
+            [KeywordCtx, Identifier] | [GlyphCtx, Identifier] => Kind::SpecialIdentifier,
+            [_, Type] => Kind::Type,
             [_, Identifier] => Kind::Identifier,
-            [_, Literal] => Kind::Literal,
-            [_, Glyph] | [_, GlyphCtx] => Kind::Glyph,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] | [_, Bracket] => Kind::Punctuation,
             [_, Keyword] | [_, KeywordCtx] | [_, Constant] | [_, Macro] | [_, Qualifier] => {
                 Kind::Keyword
             }
@@ -0,0 +1,74 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Clojure {
+    #[regex("[a-zA-Z*+!_?<>=/.&$%-][a-zA-Z0-9*+!_?<>=/.&$%'#:-]*")]
+    Symbol,
+
+    #[regex("::?[a-zA-Z0-9*+!_?<>=/.&$%-][a-zA-Z0-9*+!_?<>=/.&$%'#:-]*")]
+    Keyword,
+
+    #[regex("\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("#\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("\\\\(.|newline|space|tab|return|backspace|formfeed|u[0-9a-fA-F]{4})")]
+    String,
+
+    #[regex("[+-]?[0-9]+(\\.[0-9]+)?([eE][+-]?[0-9]+)?[NM]?", priority = 5)]
+    #[regex("[+-]?[0-9]+/[0-9]+", priority = 5)]
+    #[regex("[+-]?0[xX][0-9a-fA-F]+N?", priority = 5)]
+    Number,
+
+    #[regex("#\\{|#_|#'|#\\?|#\\?@|#:|'|`|~|~@|@|\\^")]
+    Glyph,
+
+    #[token("(")]
+    Paren,
+
+    #[token("#(")]
+    Lambda,
+
+    #[regex(",|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("case|catch|cond|condp|do|doseq|dotimes|finally|for|if|if-let|if-not|import")]
+    #[regex("let|letfn|loop|new|ns|quote|recur|require|set!|throw|try|use|var")]
+    #[regex("when|when-let|when-not|while|binding|fn|fn\\*|->|->>|as->|cond->|some->")]
+    SpecialForm,
+
+    #[regex("def|defn|defn-|defmacro|defmulti|defmethod|defonce|defprotocol")]
+    #[regex("defrecord|defstruct|deftype")]
+    Definition,
+
+    #[regex("true|false|nil")]
+    Literal,
+
+    #[regex(";[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Clojure {
+    const LANG: &'static str = "clojure";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Clojure::*;
+
+        match tokens {
+            [Definition, Symbol] => Kind::Function,
+            // The head of a list is the function it calls
+            [Paren | Lambda, Symbol] => Kind::Function,
+            [_, Symbol] => Kind::Identifier,
+            [_, Keyword | Literal] => Kind::Literal,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph | Lambda] => Kind::Glyph,
+            [_, Paren | Punctuation] => Kind::Punctuation,
+            [_, SpecialForm | Definition] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
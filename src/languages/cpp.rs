@@ -0,0 +1,109 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Cpp {
+    #[regex("[a-zA-Z_$][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("(u8|u|U|L)?\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("(u8|u|U|L)?'([^'\\\\]|\\\\.)*'")]
+    #[regex("(u8|u|U|L)?R\"", raw_string)]
+    String,
+
+    #[regex("[0-9][0-9']*(\\.[0-9']*)?([eE][+-]?[0-9]+)?[uUlLfF]*")]
+    #[regex("0[xX][0-9a-fA-F']+[uUlL]*")]
+    #[regex("0[bB][01']+[uUlL]*")]
+    Number,
+
+    #[regex(r#"\?|:|!|\^|-|\+|\*|&|/|%|\||~|<|>|="#, priority = 3)]
+    Glyph,
+
+    #[regex(r"\.|->|::")]
+    GlyphCtx,
+
+    #[regex("\\{|\\}|\\[|\\]|\\(|\\)")]
+    Bracket,
+
+    #[regex("alignas|alignof|asm|break|case|catch|co_await|co_return|co_yield")]
+    #[regex("concept|const_cast|continue|decltype|default|delete|do|dynamic_cast")]
+    #[regex("else|export|for|friend|goto|if|import|module|new|noexcept|operator")]
+    #[regex("private|protected|public|reinterpret_cast|requires|return|sizeof")]
+    #[regex("static_assert|static_cast|switch|template|this|throw|try|typeid")]
+    #[regex("using|while")]
+    Keyword,
+
+    #[regex("#[ \t]*(define|elif|else|endif|error|if|ifdef|ifndef|include)")]
+    #[regex("#[ \t]*(line|pragma|undef|warning)")]
+    Macro,
+
+    #[regex("class|enum|namespace|struct|typename|union")]
+    KeywordCtx,
+
+    #[regex("auto|const|consteval|constexpr|constinit|explicit|extern|inline")]
+    #[regex("mutable|register|static|thread_local|typedef|virtual|volatile")]
+    Qualifier,
+
+    #[regex("bool|char|char8_t|char16_t|char32_t|double|float|int|long")]
+    #[regex("short|signed|unsigned|void|wchar_t")]
+    Type,
+
+    #[regex("false|nullptr|NULL|true")]
+    Constant,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a raw string literal, such as `R"x(...)x"`.
+fn raw_string(lex: &mut Lexer<Cpp>) -> bool {
+    let rest = lex.remainder();
+    let Some(open) = rest.find('(') else {
+        return false;
+    };
+    let delimiter = &rest[..open];
+    if delimiter.len() > 16 || delimiter.contains(|c: char| c.is_whitespace() || c == ')') {
+        return false;
+    }
+    let body = &rest[open + 1..];
+    let close = body
+        .match_indices(')')
+        .find(|(i, _)| {
+            body[i + 1..].starts_with(delimiter) && body[i + 1 + delimiter.len()..].starts_with('"')
+        })
+        .map(|(i, _)| i);
+    match close {
+        Some(close) => {
+            lex.bump(open + 1 + close + 1 + delimiter.len() + 1);
+            true
+        }
+        None => false,
+    }
+}
+
+impl Highlight for Cpp {
+    const LANG: &'static str = "cpp";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Cpp::*;
+
+        match tokens {
+            [KeywordCtx, Identifier] | [GlyphCtx, Identifier] => Kind::SpecialIdentifier,
+            [_, Type] => Kind::Type,
+            [_, Identifier] => Kind::Identifier,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] | [_, Bracket] => Kind::Punctuation,
+            [_, Keyword] | [_, KeywordCtx] | [_, Constant] | [_, Macro] | [_, Qualifier] => {
+                Kind::Keyword
+            }
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::ruby::percent_literal;
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Crystal {
+    #[regex("[a-z_][a-zA-Z0-9_]*[?!]?")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("@@[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("\\$[0-9~?]")]
+    Variable,
+
+    #[regex(":[a-zA-Z_][a-zA-Z0-9_]*[?!=]?")]
+    #[regex(":\"([^\"\\\\]|\\\\.)*\"")]
+    Symbol,
+
+    #[regex("\"([^\"\\\\#]|\\\\(.|\n)|#\\{[^}\"]*\\}|#)*\"")]
+    #[regex("'([^'\\\\]|\\\\[^']+)'")]
+    #[regex("%[wiqQrx]?[\\(\\[\\{<|]", percent_literal)]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9]+)?(_?[iuf](8|16|32|64|128))?")]
+    #[regex("0x[0-9a-fA-F_]+(_?[iu](8|16|32|64|128))?")]
+    #[regex("0b[01_]+(_?[iu](8|16|32|64|128))?")]
+    #[regex("0o[0-7_]+(_?[iu](8|16|32|64|128))?")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|=>|->|<=>|==|===|=~|\*\*|\.\.|\.\.\."#)]
+    Glyph,
+
+    #[token(".")]
+    #[token("::")]
+    GlyphCtx,
+
+    /// Colons, spaced before type annotations, as in `x : Int32`.
+    #[token(":", spaced)]
+    Colon(bool),
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[token("@[")]
+    Annotation,
+
+    #[regex("abstract|asm|begin|break|case|do|else|elsif|end|ensure|extend|for")]
+    #[regex("forall|if|in|include|instance_sizeof|next|offsetof|out|pointerof|private")]
+    #[regex("protected|require|rescue|responds_to\\?|return|select|self|sizeof|super")]
+    #[regex("then|typeof|uninitialized|unless|until|verbatim|when|while|with|yield")]
+    #[regex("getter|setter|property|raise")]
+    Keyword,
+
+    #[regex("of|as|as\\?|is_a\\?")]
+    KeywordOf,
+
+    #[regex("alias|annotation|class|enum|lib|module|struct|type|union")]
+    KeywordType,
+
+    #[regex("def|fun|macro")]
+    KeywordDef,
+
+    #[regex("true|false|nil")]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+/// Whether the colon just lexed follows whitespace.
+fn spaced(lex: &mut Lexer<Crystal>) -> bool {
+    lex.source()[..lex.span().start].ends_with([' ', '\t'])
+}
+
+impl Highlight for Crystal {
+    const LANG: &'static str = "crystal";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Crystal::*;
+
+        match tokens {
+            [KeywordType, StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordDef, Identifier | StrongIdentifier, _] => Kind::Function,
+            [Colon(true) | KeywordOf, StrongIdentifier, _] => Kind::Type,
+            // Named arguments and keys of hashes, such as `name: 1`
+            [_, Identifier, Colon(false)] => Kind::Literal,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Variable, _] => Kind::SpecialIdentifier,
+            [_, Symbol | Literal, _] => Kind::Literal,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Colon(_) | Punctuation, _] => Kind::Punctuation,
+            [_, Annotation, _] => Kind::Glyph,
+            [_, Keyword | KeywordOf | KeywordType | KeywordDef, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
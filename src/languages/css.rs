@@ -0,0 +1,181 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = Blocks)]
+pub enum Css {
+    #[regex("(--|-)?[a-zA-Z_][a-zA-Z0-9_-]*", word)]
+    #[token("%", word)]
+    Word(Context),
+
+    #[regex("#[a-zA-Z0-9_-]+", word)]
+    Hash(Context),
+
+    #[regex("\\.[a-zA-Z_-][a-zA-Z0-9_-]*")]
+    Class,
+
+    #[regex("[+-]?([0-9]+(\\.[0-9]+)?|\\.[0-9]+)")]
+    Number,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    String,
+
+    #[regex("@[a-zA-Z-]+", at_rule)]
+    AtRule,
+
+    #[regex("![ \t]*important")]
+    Important,
+
+    #[token("{", open)]
+    Open,
+
+    #[token("}", close)]
+    Close,
+
+    #[token(";", semicolon)]
+    Semicolon,
+
+    #[token(":")]
+    #[token("::")]
+    Colon,
+
+    #[token("(")]
+    Paren,
+
+    #[token(",")]
+    #[token(")")]
+    #[token("[")]
+    #[token("]")]
+    Punctuation,
+
+    #[regex("\\*|>|\\+|~|=|\\|=|\\^=|\\$=|\\*=|~=|/")]
+    Glyph,
+
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+/// Where a word is found, as told by the blocks it is in.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Context {
+    /// A selector or the prelude of an at-rule.
+    Selector,
+    /// A property or a value in a block of declarations.
+    Declaration,
+    /// The unit following a number.
+    Unit,
+}
+
+/// The blocks the lexer is in, as a stack of bits set for blocks of
+/// declarations and cleared for blocks of rules, such as those of `@media`.
+#[derive(Default)]
+pub struct Blocks {
+    stack: u64,
+    depth: u32,
+    /// Whether the next block is a block of rules.
+    rules: bool,
+    /// Whether the lexer is in the value of a variable outside of blocks,
+    /// such as `$primary: #336;` in SCSS.
+    value: bool,
+}
+
+impl Blocks {
+    fn declarations(&self) -> bool {
+        self.value || self.depth > 0 && self.depth <= 64 && self.stack & 1 == 1
+    }
+}
+
+pub(super) fn word<'s, Token>(lex: &mut Lexer<'s, Token>) -> Context
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    let before = lex.source()[..lex.span().start].chars().next_back();
+    if matches!(before, Some('0'..='9')) && !lex.slice().starts_with('#') {
+        Context::Unit
+    } else if lex.extras.declarations() {
+        Context::Declaration
+    } else {
+        Context::Selector
+    }
+}
+
+pub(super) fn at_rule<'s, Token>(lex: &mut Lexer<'s, Token>)
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    lex.extras.rules = matches!(
+        &lex.slice()[1..],
+        "media" | "supports" | "container" | "layer" | "document" | "scope"
+    );
+}
+
+pub(super) fn open<'s, Token>(lex: &mut Lexer<'s, Token>)
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    let blocks = &mut lex.extras;
+    blocks.stack = blocks.stack << 1 | !blocks.rules as u64;
+    blocks.depth += 1;
+    blocks.rules = false;
+    blocks.value = false;
+}
+
+pub(super) fn close<'s, Token>(lex: &mut Lexer<'s, Token>)
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    let blocks = &mut lex.extras;
+    if blocks.depth > 0 {
+        blocks.stack >>= 1;
+        blocks.depth -= 1;
+    }
+}
+
+pub(super) fn semicolon<'s, Token>(lex: &mut Lexer<'s, Token>)
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    // Ends an at-rule without a block, such as `@layer base;`
+    lex.extras.rules = false;
+    lex.extras.value = false;
+}
+
+#[cfg(feature = "lang-scss")]
+pub(super) fn variable<'s, Token>(lex: &mut Lexer<'s, Token>)
+where
+    Token: Logos<'s, Source = str, Extras = Blocks>,
+{
+    if lex.remainder().trim_start().starts_with(':') {
+        lex.extras.value = true;
+    }
+}
+
+impl Highlight for Css {
+    const LANG: &'static str = "css";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Context::*;
+        use Css::*;
+
+        match tokens {
+            [_, Word(Unit), _] => Kind::Type,
+            [_, Word(Selector), _] => Kind::StrongIdentifier,
+            [_, Hash(Selector) | Class, _] => Kind::SpecialIdentifier,
+            [Open | Semicolon | None, Word(Declaration), Colon] => Kind::Identifier,
+            [_, Word(Declaration), Paren] => Kind::Function,
+            [_, Word(Declaration), _] => Kind::Literal,
+            [_, Hash(_) | Number, _] => Kind::Number,
+            [_, String, _] => Kind::String,
+            [_, AtRule | Important, _] => Kind::Keyword,
+            [_, Glyph, _] => Kind::Operator,
+            [_, Open | Close | Semicolon | Colon | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+// Every token spans the rest of its line, so that each one starts a line
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Diff {
+    #[regex("\\+[^\n]*", priority = 4)]
+    Inserted,
+
+    #[regex("-[^\n]*", priority = 4)]
+    Deleted,
+
+    #[regex("(\\+\\+\\+|---) [^\n]*", priority = 6)]
+    File,
+
+    #[regex("@@[^\n]*", priority = 4)]
+    Hunk,
+
+    #[regex("diff [^\n]*", priority = 6)]
+    #[regex("index [^\n]*", priority = 6)]
+    #[regex("(new|deleted) file mode[^\n]*", priority = 6)]
+    #[regex("(rename|copy) (from|to) [^\n]*", priority = 6)]
+    #[regex("similarity index[^\n]*", priority = 6)]
+    Header,
+
+    #[regex("\\\\[^\n]*", priority = 4)]
+    Comment,
+
+    #[regex("[^\n]+", priority = 1)]
+    Context,
+
+    None,
+}
+
+impl Highlight for Diff {
+    const LANG: &'static str = "diff";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Diff::*;
+
+        match tokens {
+            [_, Inserted] => Kind::Inserted,
+            [_, Deleted] => Kind::Deleted,
+            [_, File] => Kind::StrongIdentifier,
+            [_, Hunk] => Kind::SpecialIdentifier,
+            [_, Header] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
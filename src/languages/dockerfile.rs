@@ -0,0 +1,59 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Dockerfile {
+    #[regex(
+        "(?i)add|arg|cmd|copy|entrypoint|env|expose|from|healthcheck|label",
+        priority = 8
+    )]
+    #[regex(
+        "(?i)maintainer|onbuild|run|shell|stopsignal|user|volume|workdir",
+        priority = 8
+    )]
+    Instruction,
+
+    #[regex("[^\\s\\\\\"$#][^\\s\\\\\"$]*")]
+    Word,
+
+    #[regex("--[a-zA-Z][a-zA-Z0-9-]*(=[^\\s]*)?", priority = 8)]
+    Flag,
+
+    #[regex("\\$[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("\\$\\{[^}\n]*\\}")]
+    Variable,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    String,
+
+    #[regex("\\\\[ \t]*\n")]
+    Continuation,
+
+    #[token("\n")]
+    Newline,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Dockerfile {
+    const LANG: &'static str = "dockerfile";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Dockerfile::*;
+
+        match tokens {
+            // Instructions and comments only start lines
+            [None | Newline, Instruction] => Kind::Keyword,
+            [None | Newline, Comment] => Kind::Comment,
+            [_, Flag] => Kind::SpecialIdentifier,
+            [_, Variable] => Kind::Identifier,
+            [_, String] => Kind::String,
+            [_, Continuation] => Kind::Glyph,
+            _ => Kind::None,
+        }
+    }
+}
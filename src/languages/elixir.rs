@@ -0,0 +1,128 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Elixir {
+    #[regex("[a-z_][a-zA-Z0-9_]*[?!]?")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex(":[a-zA-Z_][a-zA-Z0-9_]*[?!]?")]
+    #[regex(":\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("[a-z_][a-zA-Z0-9_]*[?!]?:")]
+    Atom,
+
+    #[regex("@[a-z_][a-zA-Z0-9_]*")]
+    Attribute,
+
+    #[regex("\"([^\"\\\\#]|\\\\(.|\n)|#\\{[^}\"]*\\}|#)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\]|\\\\(.|\n))*'")]
+    String,
+
+    #[regex("~[a-zA-Z]+[/|\"'(\\[{<]", sigil)]
+    Sigil,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[oO][0-7_]+")]
+    #[regex("0[bB][01_]+")]
+    #[regex("\\?(.|\\\\.)")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|<|>|=|!|&|\||\^|~|\\\\|<>|\+\+|--|\|>|<-|->|=>|::|\.\.|=~"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|%|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("after|alias|and|case|catch|cond|do|else|end|fn|for|if|import|in")]
+    #[regex("not|or|quote|raise|receive|require|rescue|try|unless|unquote|use|when|with")]
+    Keyword,
+
+    #[regex("defmodule|defprotocol|defimpl|defstruct|defexception")]
+    KeywordModule,
+
+    #[regex("def|defp|defmacro|defmacrop|defguard|defguardp|defdelegate")]
+    KeywordDef,
+
+    #[regex("true|false|nil")]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a sigil, such as `~r/a|b/i` or `~w(a b)`, up to its closing
+/// delimiter and modifiers.
+fn sigil(lex: &mut Lexer<Elixir>) {
+    let open = lex.slice().chars().next_back().unwrap_or('/');
+    let rest = lex.remainder();
+    let (close, skip) = match open {
+        '(' => (")", 0),
+        '[' => ("]", 0),
+        '{' => ("}", 0),
+        '<' => (">", 0),
+        '"' if rest.starts_with("\"\"") => ("\"\"\"", 2),
+        '\'' if rest.starts_with("''") => ("'''", 2),
+        '"' => ("\"", 0),
+        '\'' => ("'", 0),
+        '|' => ("|", 0),
+        _ => ("/", 0),
+    };
+    let mut end = rest.len();
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(skip) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if rest[i..].starts_with(close) {
+            end = i + close.len();
+            break;
+        }
+    }
+    let modifiers = rest[end..].len()
+        - rest[end..]
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+            .len();
+    lex.bump(end + modifiers);
+}
+
+impl Highlight for Elixir {
+    const LANG: &'static str = "elixir";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Elixir::*;
+
+        match tokens {
+            [KeywordModule, StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordDef, Identifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::Function,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Atom | Literal, _] => Kind::Literal,
+            [_, Attribute, _] => Kind::Glyph,
+            [_, String | Sigil, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordModule | KeywordDef, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
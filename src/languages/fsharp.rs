@@ -0,0 +1,89 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum FSharp {
+    #[regex("[a-z_][a-zA-Z0-9_']*")]
+    #[regex("``[^`\n]+``")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_']*")]
+    StrongIdentifier,
+
+    #[regex("'[a-zA-Z_][a-zA-Z0-9_]*")]
+    TypeVariable,
+
+    #[regex("\\$?\"([^\"\\\\]|\\\\(.|\n))*\"B?")]
+    #[regex("(@\\$?|\\$@)\"([^\"]|\"\")*\"B?")]
+    #[regex("\\$*\"\"\"", triple_quoted)]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'B?")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?[a-zA-Z]*")]
+    #[regex("0[xX][0-9a-fA-F_]+[a-zA-Z]*")]
+    #[regex("0[oO][0-7_]+[a-zA-Z]*")]
+    #[regex("0[bB][01_]+[a-zA-Z]*")]
+    Number,
+
+    #[regex(r"[!$%&*+\-./:<=>?@\^|~]+")]
+    #[token("(*)")]
+    Glyph,
+
+    #[token("[<")]
+    #[token(">]")]
+    Attribute,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("abstract|and|as|assert|base|begin|class|default|delegate|do|done")]
+    #[regex("downcast|downto|elif|else|end|exception|extern|for|fun|function")]
+    #[regex("global|if|in|inherit|inline|interface|internal|lazy|let|match|member")]
+    #[regex("mutable|namespace|new|not|of|open|or|override|private|public|rec")]
+    #[regex("return|select|static|struct|then|to|try|upcast|use|val|void|when")]
+    #[regex("while|with|yield|(let|use|do|yield|return|match|and)!")]
+    Keyword,
+
+    #[regex("module|type")]
+    KeywordType,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("//[^\n]*")]
+    #[token("(*", |lex| crate::nested::comment(lex, "(*", "*)"))]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a triple-quoted string, such as `"""a "b" c"""`.
+fn triple_quoted(lex: &mut Lexer<FSharp>) {
+    let rest = lex.remainder();
+    lex.bump(rest.find("\"\"\"").map_or(rest.len(), |end| end + 3));
+}
+
+impl Highlight for FSharp {
+    const LANG: &'static str = "fsharp";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use FSharp::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier] => Kind::SpecialIdentifier,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, TypeVariable] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, Attribute] => Kind::Glyph,
+            [_, Punctuation] => Kind::Punctuation,
+            [_, Keyword | KeywordType] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
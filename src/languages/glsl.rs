@@ -0,0 +1,90 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Glsl {
+    #[regex("[a-zA-Z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("gl_[a-zA-Z0-9_]+")]
+    BuiltinVariable,
+
+    #[regex("[0-9]+(\\.[0-9]*)?([eE][+-]?[0-9]+)?([uUfF]|lf|LF)?")]
+    #[regex("\\.[0-9]+([eE][+-]?[0-9]+)?([fF]|lf|LF)?")]
+    #[regex("0[xX][0-9a-fA-F]+[uU]?")]
+    Number,
+
+    #[regex(r#"\?|:|!|\^|-|\+|\*|&|/|%|\||~|<|>|="#, priority = 3)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("break|case|continue|default|discard|do|else|for|if|return|switch|while")]
+    Keyword,
+
+    #[regex("attribute|uniform|varying|buffer|shared|in|out|inout|layout|const")]
+    #[regex("centroid|flat|smooth|noperspective|patch|sample|invariant|precise")]
+    #[regex("coherent|volatile|restrict|readonly|writeonly|highp|mediump|lowp|precision")]
+    Qualifier,
+
+    #[token("struct")]
+    KeywordCtx,
+
+    #[regex("#[ \t]*(define|elif|else|endif|error|extension|if|ifdef|ifndef|line)")]
+    #[regex("#[ \t]*(pragma|undef|version)")]
+    Macro,
+
+    #[regex("void|bool|int|uint|float|double|[biud]?vec[234]|d?mat[234](x[234])?")]
+    #[regex("[iu]?(sampler|image)(1D|2D|3D|Cube|2DRect|1DArray|2DArray|CubeArray|Buffer)")]
+    #[regex("[iu]?(sampler|image)(2DMS|2DMSArray)|sampler(1D|2D|Cube)(Array)?Shadow")]
+    #[regex("sampler2DRectShadow|atomic_uint")]
+    Type,
+
+    #[regex("abs|acos|asin|atan|ceil|clamp|cos|cross|dFdx|dFdy|degrees|determinant")]
+    #[regex("distance|dot|exp|exp2|faceforward|floor|fract|fwidth|inverse|inversesqrt")]
+    #[regex("length|log|log2|max|min|mix|mod|normalize|pow|radians|reflect|refract")]
+    #[regex("round|sign|sin|smoothstep|sqrt|step|tan|texelFetch|texture|textureLod")]
+    #[regex("textureSize|transpose|imageLoad|imageStore|barrier|emit|EmitVertex")]
+    #[regex("EndPrimitive")]
+    BuiltinFunction,
+
+    #[regex("true|false")]
+    Constant,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Glsl {
+    const LANG: &'static str = "glsl";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Glsl::*;
+
+        match tokens {
+            [KeywordCtx, Identifier, _] | [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, Paren] | [_, BuiltinFunction, _] => Kind::Function,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, BuiltinVariable, _] => Kind::SpecialIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | Qualifier | KeywordCtx | Macro | Constant, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Go {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("`[^`]*`")]
+    String,
+
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    Rune,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?i?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[oO][0-7_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|:=|<-|&\^|\.\.\."#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("break|case|chan|const|continue|default|defer|else|fallthrough")]
+    #[regex("for|go|goto|if|import|interface|map|package|range|return|select")]
+    #[regex("struct|switch|var")]
+    Keyword,
+
+    #[token("type")]
+    KeywordType,
+
+    #[token("func")]
+    KeywordFunc,
+
+    #[regex("true|false|iota|nil")]
+    Literal,
+
+    #[regex("any|bool|byte|comparable|complex64|complex128|error|float32|float64")]
+    #[regex("int|int8|int16|int32|int64|rune|string|uint|uint8|uint16|uint32|uint64|uintptr")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Go {
+    const LANG: &'static str = "go";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Go::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier] | [GlyphCtx, Identifier] => {
+                Kind::SpecialIdentifier
+            }
+            [KeywordFunc, Identifier | StrongIdentifier] => Kind::Function,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, Type] => Kind::Type,
+            [_, String] | [_, Rune] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] | [_, Punctuation] => Kind::Punctuation,
+            [_, Keyword] | [_, KeywordType] | [_, KeywordFunc] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GraphQl {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("\\$[a-zA-Z_][a-zA-Z0-9_]*")]
+    Variable,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    Directive,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    String,
+
+    #[regex("-?(0|[1-9][0-9]*)(\\.[0-9]+)?([eE][+-]?[0-9]+)?")]
+    Number,
+
+    #[regex("!|=|\\||&|\\.\\.\\.")]
+    Glyph,
+
+    #[regex(",|:|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("extend|implements|on|repeatable|schema|subscription")]
+    Keyword,
+
+    #[regex("directive|enum|fragment|input|interface|mutation|query|scalar|type|union")]
+    KeywordCtx,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for GraphQl {
+    const LANG: &'static str = "graphql";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use GraphQl::*;
+
+        match tokens {
+            [KeywordCtx, Identifier | StrongIdentifier] => Kind::SpecialIdentifier,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::Type,
+            [_, Variable] => Kind::StrongIdentifier,
+            [_, Directive] => Kind::Glyph,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, Punctuation] => Kind::Punctuation,
+            [_, Keyword | KeywordCtx] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
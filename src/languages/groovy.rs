@@ -0,0 +1,128 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Groovy {
+    #[regex("[a-z_$][a-zA-Z0-9_$]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_$]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n$]|\\\\.|\\$\\{[^}\"\n]*\\}|\\$)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    #[regex("'''([^']|'[^']|''[^'])*'''")]
+    #[regex("\\$/([^/]|/[^$])*/\\$")]
+    String,
+
+    /// Slashes, either dividing or opening a slashy string such as `/a+b/`.
+    #[token("/", slashy)]
+    Slash(bool),
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9_]+)?[lLfFdDgGiI]?")]
+    #[regex("0[xX][0-9a-fA-F_]+[lLgGiI]?")]
+    #[regex("0[bB][01_]+[lLgGiI]?")]
+    Number,
+
+    #[regex(r#"\+|-|\*|%|&|\||\^|<|>|=|!|~|\?|->|::|\?\.|\?:|\*\.|\.\.|\.\.<|=~|==~|<=>|/="#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*(\\.[a-zA-Z_][a-zA-Z0-9_]*)*")]
+    Annotation,
+
+    #[regex("abstract|as|assert|break|case|catch|const|continue|def|default|do|else")]
+    #[regex("extends|final|finally|for|goto|if|implements|import|in|instanceof|new")]
+    #[regex("package|private|protected|public|return|static|super|switch|synchronized")]
+    #[regex("this|throw|throws|transient|try|var|volatile|while")]
+    Keyword,
+
+    #[regex("class|enum|interface|record|trait")]
+    KeywordType,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("boolean|byte|char|double|float|int|long|short|void")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a slashy string, where an operand can start: after an operator, an
+/// opening bracket, a comma or a keyword. A slash that would close it right before another
+/// one is left as division, keeping the `//` comment that follows.
+fn slashy(lex: &mut Lexer<Groovy>) -> bool {
+    let before = lex.source()[..lex.span().start].trim_end();
+    if before.ends_with(|c: char| ")]}\"'".contains(c)) {
+        return false;
+    }
+    let word = before
+        .rsplit(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+        .next()
+        .unwrap_or_default();
+    if !word.is_empty() {
+        let mut words = Groovy::lexer(word);
+        let keyword = words.next() == Some(Ok(Groovy::Keyword)) && words.slice() == word;
+        if !keyword {
+            return false;
+        }
+    }
+    let rest = lex.remainder();
+    let mut escaped = false;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '/' if !escaped => {
+                if rest[i + 1..].starts_with('/') {
+                    return false;
+                }
+                lex.bump(i + 1);
+                return true;
+            }
+            '\\' => escaped = !escaped,
+            _ => escaped = false,
+        }
+    }
+    false
+}
+
+impl Highlight for Groovy {
+    const LANG: &'static str = "groovy";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Groovy::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, String | Slash(true), _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Annotation, _] => Kind::Glyph,
+            [_, Glyph | Slash(false), _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Haskell {
+    #[regex("[a-z_][a-zA-Z0-9_']*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_']*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[oO][0-7_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[token("::")]
+    Signature,
+
+    #[regex(r"[!#$%&*+./<=>?@\\^|~:-]+")]
+    #[regex("`[a-zA-Z_][a-zA-Z0-9_'.]*`")]
+    Glyph,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("case|class|default|deriving|do|else|family|forall|foreign|if|import")]
+    #[regex("in|infix|infixl|infixr|instance|let|mdo|module|newtype|of|qualified")]
+    #[regex("then|type|where|as|hiding")]
+    Keyword,
+
+    #[token("data")]
+    KeywordData,
+
+    #[regex(r"---*([^!#$%&*+./<=>?@\\^|~:\n-][^\n]*)?", priority = 8)]
+    #[token("{-", |lex| crate::nested::comment(lex, "{-", "-}"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Haskell {
+    const LANG: &'static str = "haskell";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Haskell::*;
+
+        match tokens {
+            [KeywordData, StrongIdentifier, _] => Kind::SpecialIdentifier,
+            // The name of a type signature, such as `main` in `main :: IO ()`
+            [_, Identifier, Signature] => Kind::Function,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Signature | Glyph, _] => Kind::Operator,
+            [_, Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordData, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = bool)]
+pub enum Html {
+    #[token("<", |lex| open(lex, Html::Open))]
+    #[token("</", |lex| open(lex, Html::Open))]
+    Open,
+
+    #[token(">", |lex| close(lex, Html::Close))]
+    #[token("/>", |lex| close(lex, Html::Close))]
+    Close,
+
+    #[regex("[a-zA-Z_:@][a-zA-Z0-9_:.@-]*", |lex| in_tag(lex, Html::Name))]
+    Name,
+
+    #[token("=", |lex| in_tag(lex, Html::Equals))]
+    Equals,
+
+    #[regex("\"[^\"]*\"", |lex| in_tag(lex, Html::String))]
+    #[regex("'[^']*'", |lex| in_tag(lex, Html::String))]
+    String,
+
+    #[regex("&[a-zA-Z][a-zA-Z0-9]*;")]
+    #[regex("&#[0-9]+;")]
+    #[regex("&#[xX][0-9a-fA-F]+;")]
+    Entity,
+
+    #[regex("<![dD][oO][cC][tT][yY][pP][eE][^>]*>")]
+    Doctype,
+
+    #[regex("<!--([^-]|-[^-]|--[^>])*-->")]
+    Comment,
+
+    /// Text outside of tags, left as it is.
+    Text,
+
+    None,
+}
+
+/// Tokens of markup with text between its tags.
+pub(super) trait Tagged {
+    /// The token of the text outside of tags.
+    const TEXT: Self;
+}
+
+impl Tagged for Html {
+    const TEXT: Self = Html::Text;
+}
+
+/// Enter a tag, unless the `<` stands alone in the text.
+pub(super) fn open<'s, Token>(lex: &mut Lexer<'s, Token>, token: Token) -> Token
+where
+    Token: Logos<'s, Source = str, Extras = bool> + Tagged,
+{
+    if lex
+        .remainder()
+        .starts_with(|c: char| c.is_ascii_alphabetic())
+    {
+        lex.extras = true;
+        token
+    } else {
+        Token::TEXT
+    }
+}
+
+/// Leave a tag, unless the `>` is part of the text.
+pub(super) fn close<'s, Token>(lex: &mut Lexer<'s, Token>, token: Token) -> Token
+where
+    Token: Logos<'s, Source = str, Extras = bool> + Tagged,
+{
+    if lex.extras {
+        lex.extras = false;
+        token
+    } else {
+        Token::TEXT
+    }
+}
+
+/// Keep tokens only found within tags, taking them as text elsewhere.
+pub(super) fn in_tag<'s, Token>(lex: &mut Lexer<'s, Token>, token: Token) -> Token
+where
+    Token: Logos<'s, Source = str, Extras = bool> + Tagged,
+{
+    if lex.extras {
+        token
+    } else {
+        Token::TEXT
+    }
+}
+
+impl Highlight for Html {
+    const LANG: &'static str = "html";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Html::*;
+
+        match tokens {
+            [Open, Name] => Kind::Keyword,
+            [Equals, Name] | [_, String] => Kind::String,
+            [_, Name] => Kind::Identifier,
+            [_, Open | Close | Equals] => Kind::Punctuation,
+            [_, Entity] => Kind::Literal,
+            [_, Doctype] => Kind::Glyph,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+/// Tokens carry whether they are part of a value, which runs from the first
+/// separator of a line to its end.
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = bool)]
+pub enum Ini {
+    #[regex("[^\\s=:;#\\[][^\\s=:]*", value)]
+    Word(bool),
+
+    #[regex("\\[[^\\]\n]*\\]", value)]
+    Section(bool),
+
+    #[token("=", separator)]
+    #[token(":", separator)]
+    Separator(bool),
+
+    #[regex("[;#][^\\s]*", comment)]
+    Comment,
+
+    #[token("\n", |lex| lex.extras = false)]
+    Newline,
+
+    None,
+}
+
+/// Whether the token is part of a value.
+fn value(lex: &mut Lexer<Ini>) -> bool {
+    lex.extras
+}
+
+/// Start the value at the first separator of the line.
+fn separator(lex: &mut Lexer<Ini>) -> bool {
+    core::mem::replace(&mut lex.extras, true)
+}
+
+/// Lex the rest of a comment, which ends a value when it follows whitespace. The first word
+/// of a value is kept, even if it starts with `;` or `#`.
+fn comment(lex: &mut Lexer<Ini>) -> Ini {
+    let before = &lex.source()[..lex.span().start];
+    if lex.extras && (!before.ends_with([' ', '\t']) || before.trim_end().ends_with(['=', ':'])) {
+        return Ini::Word(true);
+    }
+    let rest = lex.remainder();
+    lex.bump(rest.find('\n').unwrap_or(rest.len()));
+    Ini::Comment
+}
+
+impl Highlight for Ini {
+    const LANG: &'static str = "ini";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Ini::*;
+
+        match tokens {
+            [_, Word(true) | Section(true) | Separator(true)] => Kind::String,
+            [_, Word(false)] => Kind::Identifier,
+            [_, Section(false)] => Kind::StrongIdentifier,
+            [_, Separator(false)] => Kind::Punctuation,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Java {
+    #[regex("[a-z_$][a-zA-Z0-9_$]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_$]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?[lLfFdD]?")]
+    #[regex("\\.[0-9][0-9_]*([eE][+-]?[0-9_]+)?[fFdD]?")]
+    #[regex("0[xX][0-9a-fA-F_]+[lL]?")]
+    #[regex("0[bB][01_]+[lL]?")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|->|::"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*(\\.[a-zA-Z_][a-zA-Z0-9_]*)*")]
+    Annotation,
+
+    #[regex("abstract|assert|break|case|catch|const|continue|default|do|else")]
+    #[regex("exports|extends|final|finally|for|goto|if|implements|import|instanceof")]
+    #[regex("module|native|new|non-sealed|package|permits|private|protected|public")]
+    #[regex("requires|return|sealed|static|strictfp|super|switch|synchronized|this")]
+    #[regex("throw|throws|transient|try|var|volatile|while|yield")]
+    Keyword,
+
+    #[regex("class|enum|interface|record")]
+    KeywordType,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("boolean|byte|char|double|float|int|long|short|void")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Java {
+    const LANG: &'static str = "java";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Java::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Annotation, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
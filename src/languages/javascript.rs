@@ -9,11 +9,13 @@ pub enum JavaScript {
     #[regex("\"([^\"\\\\]|\\\\[.\n])*\"")]
     #[regex("`([^`]|\\\\`)*`")]
     #[regex("'([^']|\\\\')'")]
+    String,
+
     #[regex("[0-9][0-9]*")]
     #[regex("0[xX][0-9a-fA-F]+")]
     #[regex("0[oO][0-7]+")]
     #[regex("0[bB][01]+")]
-    Literal,
+    Number,
 
     #[regex(r#"\?|:|!|\^|-|\+|\*|&|/|\|<|>|=|=>|_"#, priority = 3)]
     Glyph,
@@ -29,9 +31,12 @@ pub enum JavaScript {
     #[regex("true|try|typeof|var|void|while|with|yield")]
     Keyword,
 
-    #[regex("function|class")]
+    #[regex("class")]
     KeywordCtx,
 
+    #[regex("function")]
+    KeywordFunction,
+
     #[regex("undefined|Object|Array|Number|String|NaN|Infinity|Date|Math")]
     Special,
 
@@ -45,17 +50,20 @@ impl Highlight for JavaScript {
     const LANG: &'static str = "js";
     const START: Self = Self::None;
 
-    fn kind(tokens: &[Self; 2]) -> Kind {
+    fn kind(tokens: &[Self]) -> Kind {
         use JavaScript::*;
 
         match tokens {
             [KeywordCtx, Identifier] | [GlyphCtx, Identifier] | [_, Special] => {
                 Kind::SpecialIdentifier
             }
+            [KeywordFunction, Identifier] => Kind::Function,
             [_, Identifier] => Kind::Identifier,
-            [_, Literal] => Kind::Literal,
-            [_, Glyph] | [_, GlyphCtx] => Kind::Glyph,
-            [_, Keyword] | [_, KeywordCtx] => Kind::Keyword,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] => Kind::Punctuation,
+            [_, Keyword] | [_, KeywordCtx] | [_, KeywordFunction] => Kind::Keyword,
             [_, Comment] => Kind::Comment,
             _ => Kind::None,
         }
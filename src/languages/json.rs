@@ -0,0 +1,42 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Json {
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    String,
+
+    #[regex("-?(0|[1-9][0-9]*)(\\.[0-9]+)?([eE][+-]?[0-9]+)?")]
+    Number,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[token(":")]
+    Colon,
+
+    #[regex(",|\\{|\\}|\\[|\\]")]
+    Punctuation,
+
+    None,
+}
+
+impl Highlight for Json {
+    const LANG: &'static str = "json";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Json::*;
+
+        match tokens {
+            // Object keys are told apart from values by the colon following them
+            [_, String, Colon] => Kind::Identifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Colon | Punctuation, _] => Kind::Punctuation,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Julia {
+    #[regex("[a-z_][a-zA-Z0-9_!]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_!]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\$]|\\\\(.|\n)|\\$\\([^)\"\n]*\\)|\\$)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9]+)?(im)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|\\|%|\^|&|\||<|>|=|!|~|\?|:|::|->|=>|\.\.\.|\|>|\$|'"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_!]*")]
+    Macro,
+
+    #[regex("baremodule|begin|break|catch|const|continue|do|else|elseif|end|export")]
+    #[regex("finally|for|global|if|import|in|isa|let|local|module|public|quote")]
+    #[regex("return|try|using|where|while")]
+    Keyword,
+
+    #[regex("abstract|mutable|primitive|struct|type")]
+    KeywordType,
+
+    #[regex("function|macro")]
+    KeywordFunction,
+
+    #[regex("true|false|nothing|missing|Inf|NaN")]
+    Literal,
+
+    #[regex("#([^=\n][^\n]*)?")]
+    #[token("#=", |lex| crate::nested::comment(lex, "#=", "=#"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Julia {
+    const LANG: &'static str = "julia";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Julia::*;
+
+        match tokens {
+            [KeywordType, StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordFunction, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Macro, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFunction, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
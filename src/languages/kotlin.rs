@@ -0,0 +1,87 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Kotlin {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    #[regex("`[^`\n]+`")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n$]|\\\\.|\\$\\{[^}\"\n]*\\}|\\$)*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9_]+)?[fFL]?")]
+    #[regex("0[xX][0-9a-fA-F_]+L?")]
+    #[regex("0[bB][01_]+L?")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&&|\|\||<|>|=|!|\?|->|\.\.|\?:|::|!!|\?\."#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*(:[a-zA-Z_][a-zA-Z0-9_]*)?")]
+    Annotation,
+
+    #[regex("abstract|as|break|by|catch|companion|const|constructor|continue|data")]
+    #[regex("do|else|enum|final|finally|for|get|if|import|in|init|inline|inner")]
+    #[regex("internal|is|lateinit|open|operator|out|override|package|private")]
+    #[regex("protected|public|reified|return|sealed|set|super|suspend|this|throw")]
+    #[regex("try|val|var|vararg|when|where|while")]
+    Keyword,
+
+    #[regex("class|interface|object|typealias")]
+    KeywordType,
+
+    #[token("fun")]
+    KeywordFun,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("//[^\n]*")]
+    #[token("/*", |lex| crate::nested::comment(lex, "/*", "*/"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Kotlin {
+    const LANG: &'static str = "kotlin";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Kotlin::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordFun, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Annotation, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFun, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
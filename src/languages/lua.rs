@@ -0,0 +1,99 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Lua {
+    #[regex("[a-zA-Z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\\n]|\\\\(.|\n))*'")]
+    #[regex("\\[=*\\[", |lex| long_bracket(lex, 0))]
+    String,
+
+    #[regex("[0-9]+(\\.[0-9]*)?([eE][+-]?[0-9]+)?")]
+    #[regex("\\.[0-9]+([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F]+(\\.[0-9a-fA-F]*)?([pP][+-]?[0-9]+)?")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|//|%|\^|#|&|~|\||<<|>>|==|~=|<=|>=|<|>|=|\.\."#)]
+    Glyph,
+
+    #[token(".")]
+    #[token(":")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|::|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("and|break|do|else|elseif|end|for|goto|if|in|local|not|or|repeat")]
+    #[regex("return|then|until|while")]
+    Keyword,
+
+    #[token("function")]
+    KeywordFunction,
+
+    #[regex("true|false|nil|\\.\\.\\.")]
+    Literal,
+
+    #[token("--", comment)]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a long bracket whose opening `[==[` ends `skip` bytes into
+/// the token, up to the closing `]==]` of the same level.
+fn long_bracket(lex: &mut Lexer<Lua>, skip: usize) {
+    let level = lex.slice()[skip..].matches('=').count();
+    let close = ["]", &"=".repeat(level), "]"].concat();
+    let rest = lex.remainder();
+    lex.bump(
+        rest.find(&close)
+            .map_or(rest.len(), |end| end + close.len()),
+    );
+}
+
+/// Lex the rest of a comment, either to the end of the line or to the end of
+/// the long bracket it starts with, such as `--[[ ... ]]`.
+fn comment(lex: &mut Lexer<Lua>) {
+    let rest = lex.remainder();
+    let level = rest
+        .strip_prefix('[')
+        .map(|rest| rest.len() - rest.trim_start_matches('=').len());
+    match level {
+        Some(level) if rest[1 + level..].starts_with('[') => {
+            lex.bump(level + 2);
+            long_bracket(lex, 2);
+        }
+        _ => lex.bump(rest.find('\n').unwrap_or(rest.len())),
+    }
+}
+
+impl Highlight for Lua {
+    const LANG: &'static str = "lua";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Lua::*;
+
+        match tokens {
+            [KeywordFunction, Identifier, _] => Kind::Function,
+            [_, Identifier, Paren | String] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordFunction, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Makefile {
+    #[regex("[^\\s:=#$()?+!\\\\]+")]
+    Word,
+
+    #[regex(
+        "include|-include|sinclude|ifeq|ifneq|ifdef|ifndef|else|endif",
+        priority = 8
+    )]
+    #[regex(
+        "define|endef|export|unexport|override|private|undefine|vpath",
+        priority = 8
+    )]
+    Directive,
+
+    #[regex("\\$[^({\\s]")]
+    #[regex("\\$\\(([^()\n]|\\([^()\n]*\\))*\\)")]
+    #[regex("\\$\\{([^{}\n]|\\{[^{}\n]*\\})*\\}")]
+    Variable,
+
+    #[regex("=|:=|::=|:::=|\\?=|\\+=|!=")]
+    Assignment,
+
+    #[token(":")]
+    #[token("::")]
+    Colon,
+
+    #[regex("\\\\[ \t]*\n")]
+    Continuation,
+
+    #[token("\n")]
+    Newline,
+
+    /// The tab starting a line of a recipe.
+    #[token("\n\t")]
+    Recipe,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Makefile {
+    const LANG: &'static str = "makefile";
+    const START: Self = Self::None;
+    const BEHIND: usize = 2;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Makefile::*;
+
+        match tokens {
+            // Targets and variables are defined at the start of lines, not in recipes
+            [_, None | Newline, Word | Variable, Colon] => Kind::Function,
+            [_, None | Newline, Word, Assignment] => Kind::SpecialIdentifier,
+            [_, None | Newline, Directive, _] => Kind::Keyword,
+            [None | Newline, Word | Variable, Colon, _] => Kind::Punctuation,
+            [_, _, Variable, _] => Kind::Identifier,
+            [_, _, Assignment, _] => Kind::Operator,
+            [_, _, Continuation, _] => Kind::Glyph,
+            [_, _, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
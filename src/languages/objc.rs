@@ -0,0 +1,109 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ObjectiveC {
+    #[regex("[a-z_$][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("@?\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\]|\\\\.)'")]
+    String,
+
+    #[regex("[0-9][0-9]*(\\.[0-9]+)?([eE][+-]?[0-9]+)?[uUlLfF]*")]
+    #[regex("0[xX][0-9a-fA-F]+[uUlL]*")]
+    #[regex("0[bB][01]+")]
+    Number,
+
+    #[regex(r#"\?|!|\^|-|\+|\*|&|/|%|\||~|<|>|="#, priority = 3)]
+    Glyph,
+
+    #[regex(r"\.|->")]
+    GlyphCtx,
+
+    #[token(":")]
+    Colon,
+
+    #[regex("\\]|\\)")]
+    Close,
+
+    #[regex(",|;|\\{|\\}|\\[|\\(")]
+    Bracket,
+
+    /// Boxed expressions and collection literals, such as `@[1, 2]`.
+    #[regex("@\\[|@\\{|@\\(|@[0-9]+|@YES|@NO")]
+    Boxed,
+
+    #[regex("asm|break|case|continue|default|defined|do|else|for|goto|if")]
+    #[regex("return|sizeof|switch|typeof|while|in|self|super")]
+    Keyword,
+
+    #[regex("@(autoreleasepool|available|catch|dynamic|encode|end|finally|import)")]
+    #[regex("@(optional|package|private|property|protected|public|required|selector)")]
+    #[regex("@(synchronized|synthesize|throw|try)")]
+    AtKeyword,
+
+    #[regex("@(class|implementation|interface|protocol)")]
+    AtKeywordCtx,
+
+    #[regex("#(define|elif|else|endif|error|if|ifdef|ifndef|import|include)")]
+    #[regex("#(line|pragma|undef|warning)")]
+    Macro,
+
+    #[regex("enum|struct|typedef|union")]
+    KeywordCtx,
+
+    #[regex("auto|const|extern|inline|register|static|volatile|nonatomic|atomic")]
+    #[regex("readonly|readwrite|nullable|nonnull|__block|__weak|__strong")]
+    Qualifier,
+
+    #[regex("bool|char|double|float|int|long|short|signed|unsigned|void")]
+    #[regex("id|instancetype|BOOL|SEL|IMP|Class")]
+    Type,
+
+    #[regex("false|true|NULL|nil|Nil|YES|NO")]
+    Constant,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for ObjectiveC {
+    const LANG: &'static str = "objective-c";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use ObjectiveC::*;
+
+        match tokens {
+            [AtKeywordCtx | KeywordCtx, Identifier | StrongIdentifier, _] => {
+                Kind::SpecialIdentifier
+            }
+            // Selectors of messages, such as `[list insert:item at:0]`,
+            // and of method declarations, such as `- (void)greet:(id)name`
+            [Identifier | StrongIdentifier | String | Number | Close, Identifier, Colon | Close] => {
+                Kind::Function
+            }
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Colon | Close | Bracket, _] => Kind::Punctuation,
+            [_, Boxed, _] => Kind::Glyph,
+            [_, Keyword | KeywordCtx | Constant | Macro | Qualifier, _] => Kind::Keyword,
+            [_, AtKeyword | AtKeywordCtx, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
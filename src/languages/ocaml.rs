@@ -0,0 +1,92 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Ocaml {
+    #[regex("[a-z_][a-zA-Z0-9_']*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_']*")]
+    #[regex("`[a-zA-Z_][a-zA-Z0-9_']*")]
+    StrongIdentifier,
+
+    #[regex("[~?][a-z_][a-zA-Z0-9_']*")]
+    Label,
+
+    #[regex("'[a-z_][a-zA-Z0-9_]*")]
+    TypeVariable,
+
+    #[regex("\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    #[regex("\\{[a-z_]*\\|", quoted_string)]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?[lLn]?")]
+    #[regex("0[xX][0-9a-fA-F_]+[lLn]?")]
+    #[regex("0[oO][0-7_]+[lLn]?")]
+    #[regex("0[bB][01_]+[lLn]?")]
+    Number,
+
+    #[regex(r"[!$%&*+\-./:<=>?@\^|~]+")]
+    Glyph,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("and|as|assert|begin|class|constraint|do|done|downto|else|end")]
+    #[regex("exception|external|for|fun|function|functor|if|in|include|inherit")]
+    #[regex("initializer|lazy|let|match|method|mutable|new|nonrec|object|of|open")]
+    #[regex("or|private|rec|sig|struct|then|to|try|val|virtual|when|while|with")]
+    Keyword,
+
+    #[regex("module|type")]
+    KeywordType,
+
+    #[regex("true|false")]
+    Literal,
+
+    #[token("(*", |lex| crate::nested::comment(lex, "(*", "*)"))]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a quoted string, such as `{id|...|id}`.
+fn quoted_string(lex: &mut Lexer<Ocaml>) {
+    let id = &lex.slice()[1..lex.slice().len() - 1];
+    let rest = lex.remainder();
+    let mut close = 0;
+    while let Some(bar) = rest[close..].find('|') {
+        close += bar + 1;
+        if rest[close..].starts_with(id) && rest[close + id.len()..].starts_with('}') {
+            lex.bump(close + id.len() + 1);
+            return;
+        }
+    }
+    lex.bump(rest.len());
+}
+
+impl Highlight for Ocaml {
+    const LANG: &'static str = "ocaml";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Ocaml::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier] => Kind::SpecialIdentifier,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, Label] => Kind::SpecialIdentifier,
+            [_, TypeVariable] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, Punctuation] => Kind::Punctuation,
+            [_, Keyword | KeywordType] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = Heredoc)]
+pub enum Perl {
+    #[regex("[a-zA-Z_][a-zA-Z0-9_]*(::[a-zA-Z_][a-zA-Z0-9_]*)*")]
+    Identifier,
+
+    #[regex("[$@%][a-zA-Z_][a-zA-Z0-9_]*(::[a-zA-Z_][a-zA-Z0-9_]*)*")]
+    #[regex("\\$#[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("\\$[0-9&`'+!@/\\\\,;.]")]
+    #[regex("[$@%]\\{[a-zA-Z_][a-zA-Z0-9_]*\\}")]
+    Variable,
+
+    #[regex("\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\]|\\\\(.|\n))*'")]
+    #[regex("`([^`\\\\]|\\\\(.|\n))*`")]
+    #[regex("(q|qq|qw|qr|m|s|tr|y)[^\\sa-zA-Z0-9_,;=)\\]}>]", quote_like)]
+    #[regex(
+        "<<~?(\"[a-zA-Z_][a-zA-Z0-9_]*\"|'[a-zA-Z_][a-zA-Z0-9_]*'|[a-zA-Z_][a-zA-Z0-9_]*)",
+        heredoc
+    )]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|\.|=~|!~|=>|->|\*\*|\.\.|<=>|//"#)]
+    Glyph,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("and|cmp|continue|default|die|do|else|elsif|eq|for|foreach|ge|given|gt")]
+    #[regex("if|last|le|local|lt|my|ne|next|no|not|or|our|package|print|redo")]
+    #[regex("require|return|say|state|unless|until|use|when|while|xor|BEGIN|END")]
+    Keyword,
+
+    #[token("sub")]
+    KeywordSub,
+
+    #[regex("undef|__FILE__|__LINE__|__PACKAGE__|__DATA__|__END__")]
+    Literal,
+
+    #[token("\n", newline)]
+    Newline(bool),
+
+    #[regex("#[^\n]*")]
+    #[regex("=[a-zA-Z][a-zA-Z0-9]*", pod)]
+    Comment,
+
+    None,
+}
+
+/// The terminator of a heredoc whose body starts on the next line.
+#[derive(Default)]
+pub struct Heredoc {
+    label: Option<(usize, usize)>,
+    indented: bool,
+}
+
+/// Lex the rest of a quote-like operator, such as `qw(a b)` or `s/a/b/g`.
+fn quote_like(lex: &mut Lexer<Perl>) {
+    let slice = lex.slice();
+    let open = slice.chars().next_back().unwrap_or('/');
+    let parts = match &slice[..slice.len() - open.len_utf8()] {
+        "s" | "tr" | "y" => 2,
+        _ => 1,
+    };
+    let close = match open {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        other => other,
+    };
+    let rest = lex.remainder();
+    let mut end = delimited(rest, open, close);
+    if parts == 2 {
+        // Bracketed substitutions have a second pair of brackets, such as `s{a}{b}`
+        let second = &rest[end..];
+        end += match second.trim_start().chars().next() {
+            Some(open) if open != close => {
+                let skipped = second.len() - second.trim_start().len() + open.len_utf8();
+                let close = match open {
+                    '(' => ')',
+                    '[' => ']',
+                    '{' => '}',
+                    '<' => '>',
+                    other => other,
+                };
+                skipped + delimited(&second[skipped..], open, close)
+            }
+            _ => delimited(second, close, close),
+        };
+    }
+    let flags = rest[end..].len()
+        - rest[end..]
+            .trim_start_matches(|c: char| c.is_ascii_lowercase())
+            .len();
+    lex.bump(end + flags);
+}
+
+/// The length of `rest` up to and including the `close` ending it,
+/// skipping escapes and nested pairs of `open` and `close`.
+fn delimited(rest: &str, open: char, close: char) -> usize {
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in rest.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == close && depth == 0 {
+            return i + c.len_utf8();
+        } else if c == close {
+            depth -= 1;
+        } else if c == open {
+            depth += 1;
+        }
+    }
+    rest.len()
+}
+
+/// Remember the terminator of a heredoc, whose body is lexed with the next newline.
+fn heredoc(lex: &mut Lexer<Perl>) {
+    let slice = lex.slice();
+    let indented = slice.starts_with("<<~");
+    let label = slice
+        .trim_start_matches(['<', '~'])
+        .trim_matches(['"', '\'']);
+    let start = lex.span().end - label.len() - (slice.ends_with(['"', '\'']) as usize);
+    lex.extras = Heredoc {
+        label: Some((start, label.len())),
+        indented,
+    };
+}
+
+/// Lex the body of a pending heredoc following a newline, if any.
+fn newline(lex: &mut Lexer<Perl>) -> bool {
+    let Some((start, len)) = lex.extras.label.take() else {
+        return false;
+    };
+    let label = &lex.source()[start..start + len];
+    let mut offset = 0;
+    for line in lex.remainder().split_inclusive('\n') {
+        let trimmed = line.trim_end_matches('\n');
+        let trimmed = match lex.extras.indented {
+            true => trimmed.trim_start(),
+            false => trimmed,
+        };
+        offset += line.len();
+        if trimmed == label {
+            offset -= line.len() - line.trim_end_matches('\n').len();
+            break;
+        }
+    }
+    lex.bump(offset);
+    true
+}
+
+/// Lex the rest of POD documentation starting a line, up to its `=cut` line.
+fn pod(lex: &mut Lexer<Perl>) -> bool {
+    let start = lex.span().start;
+    if start > 0 && !lex.source()[..start].ends_with('\n') {
+        return false;
+    }
+    let rest = lex.remainder();
+    let end = rest.find("\n=cut").map_or(rest.len(), |end| {
+        end + rest[end + 1..]
+            .find('\n')
+            .map_or(rest.len() - end, |line| line + 1)
+    });
+    lex.bump(end);
+    true
+}
+
+impl Highlight for Perl {
+    const LANG: &'static str = "perl";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Perl::*;
+
+        match tokens {
+            [KeywordSub, Identifier] => Kind::Function,
+            [_, Identifier] => Kind::Identifier,
+            [_, Variable] => Kind::SpecialIdentifier,
+            [_, String | Newline(true)] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, Punctuation] => Kind::Punctuation,
+            [_, Keyword | KeywordSub] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = Mode)]
+pub enum Php {
+    #[regex("(?i)<\\?php", open)]
+    #[token("<?=", open)]
+    #[token("<?", open)]
+    Open,
+
+    #[token("?>", close)]
+    Close,
+
+    #[regex("[a-z_][a-zA-Z0-9_]*", |lex| code(lex, Php::Identifier))]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*", |lex| code(lex, Php::StrongIdentifier))]
+    StrongIdentifier,
+
+    #[regex("\\$[a-zA-Z_][a-zA-Z0-9_]*", |lex| code(lex, Php::Variable))]
+    Variable,
+
+    #[regex("\"([^\"\\\\{]|\\\\(.|\n)|\\{\\$[^}\"]*\\}|\\{)*\"", |lex| code(lex, Php::String))]
+    #[regex("'([^'\\\\]|\\\\(.|\n))*'", |lex| code(lex, Php::String))]
+    #[regex("<<<[ \t]*[a-zA-Z_\"'][a-zA-Z0-9_\"']*\n", heredoc)]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9]+)?", |lex| code(lex, Php::Number))]
+    #[regex("0[xX][0-9a-fA-F_]+", |lex| code(lex, Php::Number))]
+    #[regex("0[bB][01_]+", |lex| code(lex, Php::Number))]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|\.|=>|\?\?|<=>|\*\*"#, |lex| code(lex, Php::Glyph))]
+    Glyph,
+
+    #[token("->", |lex| code(lex, Php::GlyphCtx))]
+    #[token("?->", |lex| code(lex, Php::GlyphCtx))]
+    #[token("::", |lex| code(lex, Php::GlyphCtx))]
+    GlyphCtx,
+
+    #[token("(", |lex| code(lex, Php::Paren))]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)", |lex| code(lex, Php::Punctuation))]
+    Punctuation,
+
+    #[regex(
+        "(?i)abstract|and|as|break|case|catch|clone|const|continue|declare|default",
+        |lex| code(lex, Php::Keyword)
+    )]
+    #[regex(
+        "(?i)do|echo|else|elseif|enum|extends|final|finally|fn|for|foreach|global",
+        |lex| code(lex, Php::Keyword)
+    )]
+    #[regex(
+        "(?i)goto|if|implements|include|include_once|instanceof|insteadof|match",
+        |lex| code(lex, Php::Keyword)
+    )]
+    #[regex(
+        "(?i)namespace|new|or|print|private|protected|public|readonly|require",
+        |lex| code(lex, Php::Keyword)
+    )]
+    #[regex(
+        "(?i)require_once|return|static|switch|throw|try|use|var|while|xor|yield",
+        |lex| code(lex, Php::Keyword)
+    )]
+    Keyword,
+
+    #[regex("(?i)class|interface|trait", |lex| code(lex, Php::KeywordType))]
+    KeywordType,
+
+    #[regex("(?i)function", |lex| code(lex, Php::KeywordFunction))]
+    KeywordFunction,
+
+    #[regex("(?i)true|false|null", |lex| code(lex, Php::Literal))]
+    Literal,
+
+    #[regex(
+        "array|bool|callable|float|int|iterable|mixed|never|object|string|void",
+        |lex| code(lex, Php::Type)
+    )]
+    Type,
+
+    #[regex("(//|#)([^\n?]|\\?[^>\n])*", |lex| code(lex, Php::Comment))]
+    #[regex("/\\*([^/]|[^*]/)*\\*/", |lex| code(lex, Php::Comment))]
+    Comment,
+
+    /// Text outside of the tags, left as it is.
+    Text,
+
+    None,
+}
+
+/// Whether the lexer is in PHP code or in the text around it.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Nothing has been lexed yet.
+    #[default]
+    Start,
+    /// Within `<?php` and `?>`.
+    Code,
+    /// Outside of the tags, where the text is left as it is.
+    Text,
+}
+
+fn open(lex: &mut Lexer<Php>) {
+    lex.extras = Mode::Code;
+}
+
+fn close(lex: &mut Lexer<Php>) -> Php {
+    match lex.extras {
+        Mode::Text => text(lex),
+        _ => {
+            lex.extras = Mode::Text;
+            Php::Close
+        }
+    }
+}
+
+/// Keep tokens of code, taking the text outside of the tags as it is.
+///
+/// Code without any tags, as in most snippets, is all taken as PHP code.
+fn code(lex: &mut Lexer<Php>, token: Php) -> Php {
+    if lex.extras == Mode::Start {
+        lex.extras = match lex.source().contains("<?") {
+            true => Mode::Text,
+            false => Mode::Code,
+        };
+    }
+    match lex.extras {
+        Mode::Code => token,
+        _ => text(lex),
+    }
+}
+
+/// Lex the text up to the next opening tag.
+fn text(lex: &mut Lexer<Php>) -> Php {
+    let rest = lex.remainder();
+    lex.bump(rest.find("<?").unwrap_or(rest.len()));
+    Php::Text
+}
+
+/// Lex the rest of a heredoc or nowdoc, up to the line of its closing identifier.
+fn heredoc(lex: &mut Lexer<Php>) -> Php {
+    if code(lex, Php::String) == Php::Text {
+        return Php::Text;
+    }
+    let label = lex.slice()[3..].trim_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'');
+    let rest = lex.remainder();
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let indent = line.len() - line.trim_start().len();
+        let after = &line[indent..];
+        let ends = after
+            .strip_prefix(label)
+            .is_some_and(|after| !after.starts_with(|c: char| c.is_alphanumeric() || c == '_'));
+        if ends {
+            lex.bump(offset + indent + label.len());
+            return Php::String;
+        }
+        offset += line.len();
+    }
+    lex.bump(rest.len());
+    Php::String
+}
+
+impl Highlight for Php {
+    const LANG: &'static str = "php";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Php::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordFunction, Identifier | StrongIdentifier, _] => Kind::Function,
+            [GlyphCtx, Identifier | StrongIdentifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, Paren] => Kind::Function,
+            [_, Identifier | Variable, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Open | Close, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFunction, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
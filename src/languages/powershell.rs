@@ -0,0 +1,138 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PowerShell {
+    #[regex("[a-zA-Z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    /// Cmdlets and functions named after the `Verb-Noun` convention.
+    #[regex("[a-zA-Z]+-[a-zA-Z][a-zA-Z0-9]*")]
+    Cmdlet,
+
+    #[regex("\\$[a-zA-Z_][a-zA-Z0-9_]*(:[a-zA-Z_][a-zA-Z0-9_]*)?")]
+    #[regex("\\$\\{[^}]*\\}")]
+    #[regex("\\$[?$^]")]
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    Variable,
+
+    #[regex("-[a-zA-Z_][a-zA-Z0-9_]*")]
+    Parameter,
+
+    #[token("\"", string)]
+    #[regex("'([^']|'')*'")]
+    #[token("@\"", |lex| until(lex, "\n\"@"))]
+    #[token("@'", |lex| until(lex, "\n'@"))]
+    String,
+
+    #[regex("(?i)[0-9]+(\\.[0-9]+)?(e[+-]?[0-9]+)?(kb|mb|gb|tb|pb)?")]
+    #[regex("(?i)0x[0-9a-f]+")]
+    Number,
+
+    #[regex(r#"=|\+|-|\*|/|%|!|<|>|\||&|\+=|-=|\*=|/=|%=|\+\+|--|\.\."#)]
+    #[regex(
+        "(?i)-[ci]?(eq|ne|gt|ge|lt|le|like|notlike|match|notmatch|contains|notcontains|in|notin)",
+        priority = 8
+    )]
+    #[regex("(?i)-[ci]?(replace|split)|-join|-f", priority = 8)]
+    #[regex(
+        "(?i)-(and|or|xor|not|band|bor|bxor|bnot|shl|shr|is|isnot|as)",
+        priority = 8
+    )]
+    Glyph,
+
+    #[token(".")]
+    #[token("::")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[token("[")]
+    Bracket,
+
+    #[regex(",|;|\\)|\\]|\\{|\\}|@\\(|@\\{")]
+    Punctuation,
+
+    #[regex("(?i)begin|break|catch|class|continue|data|do|dynamicparam|else|elseif|end|enum")]
+    #[regex("(?i)exit|finally|for|foreach|from|if|in|param|process|return|switch|throw")]
+    #[regex("(?i)trap|try|until|using|while|workflow")]
+    Keyword,
+
+    #[regex("(?i)function|filter")]
+    KeywordFn,
+
+    #[regex("(?i)\\$(true|false|null)", priority = 8)]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    #[token("<#", |lex| until(lex, "#>"))]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a `"..."` string, whose `$(...)` subexpressions may
+/// contain strings of their own.
+fn string(lex: &mut Lexer<PowerShell>) {
+    lex.bump(string_len(lex.remainder()));
+}
+
+/// The length of the rest of a `"..."` string at the start of `rest`.
+fn string_len(rest: &str) -> usize {
+    let bytes = rest.as_bytes();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'`' => i += 1,
+            b'"' if depth > 0 => i += string_len(&rest[i + 1..]),
+            b'"' if bytes.get(i + 1) == Some(&b'"') => i += 1,
+            b'"' => return i + 1,
+            b'$' if bytes.get(i + 1) == Some(&b'(') => {
+                depth += 1;
+                i += 1;
+            }
+            b'(' if depth > 0 => depth += 1,
+            b')' if depth > 0 => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    rest.len()
+}
+
+/// Lex the rest of a here-string or block comment, up to and including `close`.
+fn until(lex: &mut Lexer<PowerShell>, close: &str) {
+    let rest = lex.remainder();
+    lex.bump(rest.find(close).map_or(rest.len(), |i| i + close.len()));
+}
+
+impl Highlight for PowerShell {
+    const LANG: &'static str = "powershell";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use PowerShell::*;
+
+        match tokens {
+            [KeywordFn, Identifier | Cmdlet, _] => Kind::Function,
+            // Type literals, such as `[int]`
+            [Bracket, Identifier, _] => Kind::Type,
+            [_, Identifier, Paren] | [_, Cmdlet, _] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, Variable, _] => Kind::SpecialIdentifier,
+            [_, Parameter, _] => Kind::Glyph,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Bracket | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordFn, _] => Kind::Keyword,
+            [_, Literal, _] => Kind::Literal,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
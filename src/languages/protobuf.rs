@@ -0,0 +1,81 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Protobuf {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    String,
+
+    #[regex("-?[0-9]+(\\.[0-9]+)?([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F]+")]
+    #[regex("inf|nan")]
+    Number,
+
+    #[regex("=|-|\\+")]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)|<|>")]
+    Punctuation,
+
+    #[regex("edition|extend|extensions|group|import|map|max|oneof|optional|package")]
+    #[regex("public|repeated|required|reserved|returns|stream|syntax|to|weak")]
+    Keyword,
+
+    #[regex("enum|message|service")]
+    KeywordType,
+
+    #[token("rpc")]
+    KeywordRpc,
+
+    #[token("option")]
+    KeywordOption,
+
+    #[regex("true|false")]
+    Literal,
+
+    #[regex("bool|bytes|double|fixed32|fixed64|float|int32|int64|sfixed32|sfixed64")]
+    #[regex("sint32|sint64|string|uint32|uint64")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Protobuf {
+    const LANG: &'static str = "protobuf";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Protobuf::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier] => Kind::SpecialIdentifier,
+            [KeywordRpc, Identifier | StrongIdentifier] => Kind::Function,
+            [KeywordOption, Identifier] => Kind::SpecialIdentifier,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, Type] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx | Punctuation] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordRpc | KeywordOption] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Python {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("([rRbBuUfF]|[rR][bBfF]|[bBfF][rR])?\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("([rRbBuUfF]|[rR][bBfF]|[bBfF][rR])?'([^'\\\\\n]|\\\\(.|\n))*'")]
+    #[regex("([rRbBuUfF]|[rR][bBfF]|[bBfF][rR])?\"\"\"([^\"\\\\]|\\\\(.|\n)|\"[^\"\\\\]|\"\"[^\"\\\\])*\"\"\"")]
+    #[regex(
+        "([rRbBuUfF]|[rR][bBfF]|[bBfF][rR])?'''([^'\\\\]|\\\\(.|\n)|'[^'\\\\]|''[^'\\\\])*'''"
+    )]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?[jJ]?")]
+    #[regex("\\.[0-9][0-9_]*([eE][+-]?[0-9_]+)?[jJ]?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[oO][0-7_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|=|<|>|!|&|\||\^|~|@|:=|->"#)]
+    Glyph,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_.]*")]
+    Decorator,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("and|as|assert|async|await|break|continue|del|elif|else|except")]
+    #[regex("finally|for|from|global|if|import|in|is|lambda|nonlocal|not")]
+    #[regex("or|pass|raise|return|try|while|with|yield")]
+    Keyword,
+
+    #[token("class")]
+    KeywordClass,
+
+    #[token("def")]
+    KeywordDef,
+
+    #[regex("False|None|True")]
+    Literal,
+
+    #[regex("self|cls")]
+    SelfValue,
+
+    #[regex("bool|bytes|complex|dict|float|frozenset|int|list|object|set|str|tuple")]
+    Type,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Python {
+    const LANG: &'static str = "python";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Python::*;
+
+        match tokens {
+            [KeywordClass, Identifier | StrongIdentifier]
+            | [GlyphCtx, Identifier]
+            | [_, SelfValue] => Kind::SpecialIdentifier,
+            [KeywordDef, Identifier | StrongIdentifier] => Kind::Function,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, Type] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] | [_, Punctuation] => Kind::Punctuation,
+            [_, Decorator] => Kind::Glyph,
+            [_, Keyword] | [_, KeywordClass] | [_, KeywordDef] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum R {
+    #[regex("[a-zA-Z][a-zA-Z0-9._]*")]
+    #[regex("\\.[a-zA-Z_.][a-zA-Z0-9._]*")]
+    #[regex("`[^`\n]+`")]
+    Identifier,
+
+    #[regex("\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\]|\\\\(.|\n))*'")]
+    String,
+
+    #[regex("[0-9]+(\\.[0-9]*)?([eE][+-]?[0-9]+)?[Li]?")]
+    #[regex("\\.[0-9]+([eE][+-]?[0-9]+)?[Li]?")]
+    #[regex("0[xX][0-9a-fA-F]+[Li]?")]
+    Number,
+
+    #[regex("<-|<<-|->|->>|=")]
+    Assignment,
+
+    #[regex(r#"\+|-|\*|/|\^|<|>|<=|>=|==|!=|!|&|&&|\||\|\||~|\?|:|::|:::|\$|@|\|>"#)]
+    #[regex("%[^%\n]*%")]
+    Glyph,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\)|\\\\")]
+    Punctuation,
+
+    #[regex("break|else|for|if|in|next|repeat|while")]
+    Keyword,
+
+    #[token("function")]
+    KeywordFunction,
+
+    #[regex("TRUE|FALSE|NULL|NA|NaN|Inf|NA_integer_|NA_real_|NA_character_")]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for R {
+    const LANG: &'static str = "r";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use R::*;
+
+        match tokens {
+            [_, Identifier, Paren] => Kind::Function,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Assignment | Glyph, _] => Kind::Operator,
+            [_, Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordFunction, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
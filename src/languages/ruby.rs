@@ -0,0 +1,134 @@
+use crate::{Highlight, Kind};
+use logos::{Lexer, Logos};
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Ruby {
+    #[regex("[a-z_][a-zA-Z0-9_]*[?!]?")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("@@[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("\\$[a-zA-Z_][a-zA-Z0-9_]*")]
+    Variable,
+
+    #[regex(":[a-zA-Z_][a-zA-Z0-9_]*[?!=]?")]
+    Symbol,
+
+    #[regex("\"([^\"\\\\#]|\\\\(.|\n)|#\\{[^}\"]*\\}|#)*\"")]
+    #[regex("'([^'\\\\]|\\\\(.|\n))*'")]
+    #[regex("%[wWiIqQ]?[\\(\\[\\{<|!/]", percent_literal)]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|=>|->|<=>|==|=~|\*\*|\.\.|\.\.\."#)]
+    Glyph,
+
+    #[token(".")]
+    #[token("::")]
+    GlyphCtx,
+
+    #[token(":")]
+    Colon,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("alias|and|begin|break|case|do|else|elsif|end|ensure|for|if|in|next")]
+    #[regex("not|or|redo|rescue|retry|return|self|super|then|undef|unless|until")]
+    #[regex("when|while|yield|defined\\?|__method__|attr_accessor|attr_reader")]
+    #[regex("attr_writer|include|extend|require|require_relative|raise")]
+    Keyword,
+
+    #[regex("class|module")]
+    KeywordType,
+
+    #[token("def")]
+    KeywordDef,
+
+    #[regex("true|false|nil")]
+    Literal,
+
+    #[regex("#[^\n]*")]
+    #[regex("=begin", block_comment)]
+    Comment,
+
+    None,
+}
+
+/// Lex the rest of a `%w[...]` or similar literal, up to its closing delimiter.
+pub(super) fn percent_literal<'s, Token>(lex: &mut Lexer<'s, Token>) -> bool
+where
+    Token: Logos<'s, Source = str>,
+{
+    let open = lex.slice().chars().next_back().unwrap_or('(');
+    let close = match open {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        other => other,
+    };
+    let mut depth = 0;
+    for (i, c) in lex.remainder().char_indices() {
+        if c == close && depth == 0 {
+            lex.bump(i + 1);
+            return true;
+        } else if c == close {
+            depth -= 1;
+        } else if c == open {
+            depth += 1;
+        }
+    }
+    false
+}
+
+/// Lex the rest of a `=begin` comment, up to the line starting with `=end`.
+fn block_comment(lex: &mut Lexer<Ruby>) -> bool {
+    let start = lex.span().start;
+    if start > 0 && !lex.source()[..start].ends_with('\n') {
+        return false;
+    }
+    let rest = lex.remainder();
+    let end = rest.find("\n=end").map_or(rest.len(), |end| {
+        end + rest[end + 1..]
+            .find('\n')
+            .map_or(rest.len() - end, |line| line + 1)
+    });
+    lex.bump(end);
+    true
+}
+
+impl Highlight for Ruby {
+    const LANG: &'static str = "ruby";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Ruby::*;
+
+        match tokens {
+            [KeywordType, StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordDef, Identifier | StrongIdentifier, _] => Kind::Function,
+            // Keys of hashes, such as `name: 1`, are symbols
+            [_, Identifier, Colon] => Kind::Literal,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Variable, _] => Kind::SpecialIdentifier,
+            [_, Symbol | Literal, _] => Kind::Literal,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Colon | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordDef, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
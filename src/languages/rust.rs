@@ -18,15 +18,20 @@ pub enum Rust {
     #[regex("b'([^']|\\\\[^'\\n \\t]*)'")]
     #[regex("br\"[^\"]*\"")]
     #[regex("br#\"#?([^#]|[^\"]#)*\"#")]
+    String,
+
     #[regex("[0-9][0-9_]*")]
     #[regex("0[xX][0-9a-fA-F_]+")]
     #[regex("0[oO][0-7_]+")]
     #[regex("0[bB][01_]+")]
-    Literal,
+    Number,
 
-    #[regex(r#"\?|!|\^|-|\+|\*|&|/|\\|<|>|=|=>|->|_|#\[[^\]]*\]|&"#)]
+    #[regex(r#"\?|!|\^|-|\+|\*|&|/|\\|<|>|=|=>|->|_|&"#)]
     Glyph,
 
+    #[regex(r"#\[[^\]]*\]")]
+    Attribute,
+
     #[regex("\\.|:", priority = 3)]
     GlyphCtx,
 
@@ -44,9 +49,12 @@ pub enum Rust {
     #[regex("override|priv|try|typeof|unsized|virtual|yield")]
     Keyword,
 
-    #[regex("fn|enum|struct|type|trait")]
+    #[regex("enum|struct|type|trait")]
     KeywordCtx,
 
+    #[regex("fn")]
+    KeywordFn,
+
     #[regex("str|bool|[ui](8|16|32|64|size)|f32|f64")]
     Special,
 
@@ -61,7 +69,7 @@ impl Highlight for Rust {
     const LANG: &'static str = "rust";
     const START: Self = Self::None;
 
-    fn kind(tokens: &[Self; 2]) -> Kind {
+    fn kind(tokens: &[Self]) -> Kind {
         use Rust::*;
 
         match tokens {
@@ -69,11 +77,16 @@ impl Highlight for Rust {
             | [KeywordCtx, Identifier]
             | [GlyphCtx, Identifier]
             | [_, Lifetime] => Kind::SpecialIdentifier,
+            [KeywordFn, Identifier] => Kind::Function,
             [_, Identifier] => Kind::Identifier,
-            [_, StrongIdentifier] | [_, Special] => Kind::StrongIdentifier,
-            [_, Literal] => Kind::Literal,
-            [_, Glyph] | [_, GlyphCtx] => Kind::Glyph,
-            [_, Keyword] | [_, KeywordCtx] => Kind::Keyword,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, Special] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph] => Kind::Operator,
+            [_, GlyphCtx] | [_, Bracket] => Kind::Punctuation,
+            [_, Attribute] => Kind::Glyph,
+            [_, Keyword] | [_, KeywordCtx] | [_, KeywordFn] => Kind::Keyword,
             [_, Comment] => Kind::Comment,
             _ => Kind::None,
         }
@@ -0,0 +1,89 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Scala {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    #[regex("`[^`\n]+`")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("([a-z][a-zA-Z0-9_]*)?\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex(r#"([a-z][a-zA-Z0-9_]*)?"""([^"]|"[^"]|""[^"])*""""#)]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    String,
+
+    #[regex("'[a-zA-Z_][a-zA-Z0-9_]*")]
+    Symbol,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9_]+)?[lLfFdD]?")]
+    #[regex("0[xX][0-9a-fA-F_]+[lL]?")]
+    Number,
+
+    #[regex(r#"[+\-*/%&|^<>=!~?:#]+"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[token(":", priority = 3)]
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_.]*")]
+    Annotation,
+
+    #[regex("abstract|case|catch|do|else|enum|export|extends|final|finally|for")]
+    #[regex("forSome|given|if|implicit|import|lazy|match|new|override|package")]
+    #[regex("private|protected|return|sealed|super|then|this|throw|try|using|val")]
+    #[regex("var|while|with|yield")]
+    Keyword,
+
+    #[regex("class|object|trait|type")]
+    KeywordType,
+
+    #[token("def")]
+    KeywordDef,
+
+    #[regex("true|false|null")]
+    Literal,
+
+    #[regex("//[^\n]*")]
+    #[token("/*", |lex| crate::nested::comment(lex, "/*", "*/"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Scala {
+    const LANG: &'static str = "scala";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Scala::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordDef, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal | Symbol, _] => Kind::Literal,
+            [_, Annotation, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordDef, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Scheme {
+    #[regex("[a-zA-Z!$%&*/<=>?^_~+.-][a-zA-Z0-9!$%&*/:<=>?^_~+.@-]*")]
+    #[regex("\\|[^|]*\\|")]
+    Symbol,
+
+    #[regex("#?:[a-zA-Z0-9!$%&*/<=>?^_~+.-][a-zA-Z0-9!$%&*/:<=>?^_~+.@-]*")]
+    Keyword,
+
+    #[regex("\"([^\"\\\\]|\\\\(.|\n))*\"")]
+    #[regex("#\\\\(.|[a-zA-Z][a-zA-Z0-9]+)")]
+    String,
+
+    #[regex("[+-]?[0-9]+(\\.[0-9]+)?([eE][+-]?[0-9]+)?", priority = 5)]
+    #[regex("[+-]?[0-9]+/[0-9]+", priority = 5)]
+    #[regex("#[xX][0-9a-fA-F]+|#[bB][01]+|#[oO][0-7]+|#[dD][0-9]+")]
+    Number,
+
+    #[regex("'|`|,|,@|#'|#`|#,|#,@|#;")]
+    Glyph,
+
+    #[token("(")]
+    Paren,
+
+    #[regex("\\)|\\[|\\]|#\\(|#u8\\(")]
+    Punctuation,
+
+    #[regex("and|begin|case|case-lambda|cond|delay|do|else|guard|if|import|lambda")]
+    #[regex("let|let\\*|letrec|letrec\\*|let-values|let\\*-values|let-syntax|library")]
+    #[regex("module|or|parameterize|provide|quasiquote|quote|require|set!|setq")]
+    #[regex("syntax-rules|unless|unquote|when|export|progn")]
+    #[token("λ")]
+    SpecialForm,
+
+    #[regex("define|define-syntax|define-record-type|define-values|defun|defmacro")]
+    #[regex("defvar|defparameter|defconst|defcustom")]
+    Definition,
+
+    #[regex("#t|#f|#true|#false")]
+    Literal,
+
+    #[regex(";[^\n]*")]
+    #[token("#|", |lex| crate::nested::comment(lex, "#|", "|#"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Scheme {
+    const LANG: &'static str = "scheme";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Scheme::*;
+
+        match tokens {
+            [Definition, Symbol] => Kind::Function,
+            // The head of a list is the procedure it calls
+            [Paren, Symbol] => Kind::Function,
+            [_, Symbol] => Kind::Identifier,
+            [_, Keyword | Literal] => Kind::Literal,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Glyph] => Kind::Glyph,
+            [_, Paren | Punctuation] => Kind::Punctuation,
+            [_, SpecialForm | Definition] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::css::{at_rule, close, open, semicolon, variable, word, Blocks, Context};
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = Blocks)]
+pub enum Scss {
+    #[regex("(--|-)?[a-zA-Z_][a-zA-Z0-9_-]*", word)]
+    #[token("%", word)]
+    Word(Context),
+
+    #[regex("#[a-zA-Z0-9_-]+", word)]
+    Hash(Context),
+
+    #[regex("\\.[a-zA-Z_-][a-zA-Z0-9_-]*")]
+    #[regex("%[a-zA-Z_-][a-zA-Z0-9_-]*")]
+    Class,
+
+    #[regex("\\$[a-zA-Z_-][a-zA-Z0-9_-]*", variable)]
+    #[regex("#\\{[^}\n]*\\}")]
+    Variable,
+
+    #[regex("[+-]?([0-9]+(\\.[0-9]+)?|\\.[0-9]+)")]
+    Number,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    String,
+
+    #[regex("@[a-zA-Z-]+", at_rule)]
+    AtRule,
+
+    #[regex("![ \t]*(important|default|global|optional)")]
+    Important,
+
+    #[token("{", open)]
+    Open,
+
+    #[token("}", close)]
+    Close,
+
+    #[token(";", semicolon)]
+    Semicolon,
+
+    #[token(":")]
+    #[token("::")]
+    Colon,
+
+    #[token("(")]
+    Paren,
+
+    #[token(",")]
+    #[token(".")]
+    #[token(")")]
+    #[token("[")]
+    #[token("]")]
+    Punctuation,
+
+    #[regex("\\*|>|\\+|~|=|\\|=|\\^=|\\$=|\\*=|~=|/|&|==|!=|<|<=|>=")]
+    Glyph,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Scss {
+    const LANG: &'static str = "scss";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Context::*;
+        use Scss::*;
+
+        match tokens {
+            [_, Word(Unit), _] => Kind::Type,
+            [_, Word(Selector), _] => Kind::StrongIdentifier,
+            [_, Hash(Selector) | Class, _] => Kind::SpecialIdentifier,
+            [Open | Semicolon | None, Word(Declaration), Colon] => Kind::Identifier,
+            [_, Word(Declaration), Paren] => Kind::Function,
+            [_, Word(Declaration), _] => Kind::Literal,
+            [_, Variable, _] => Kind::SpecialIdentifier,
+            [_, Hash(_) | Number, _] => Kind::Number,
+            [_, String, _] => Kind::String,
+            [_, AtRule | Important, _] => Kind::Keyword,
+            [_, Glyph, _] => Kind::Operator,
+            [_, Open | Close | Semicolon | Colon | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -15,7 +15,7 @@ pub enum Sh {
 
     #[regex(r#""([^"\n]|\\["\n])*""#)]
     #[regex("'[^']*'")]
-    Literal,
+    String,
 
     #[token("|")]
     #[token("&")]
@@ -49,7 +49,7 @@ impl Highlight for Sh {
     const LANG: &'static str = "sh";
     const START: Sh = Self::Start;
 
-    fn kind(tokens: &[Self; 2]) -> Kind {
+    fn kind(tokens: &[Self]) -> Kind {
         use Sh::*;
 
         match tokens {
@@ -57,8 +57,8 @@ impl Highlight for Sh {
                 Kind::StrongIdentifier
             }
             [_, Parameter] => Kind::Identifier,
-            [_, Literal] => Kind::Literal,
-            [_, Glyph] => Kind::Glyph,
+            [_, String] => Kind::String,
+            [_, Glyph] => Kind::Operator,
             [_, Keyword] | [_, KeywordCtx] => Kind::Keyword,
             [_, Comment] => Kind::Comment,
             _ => Kind::None,
@@ -0,0 +1,90 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Solidity {
+    #[regex("[a-z_$][a-zA-Z0-9_$]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_$]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\.)*'")]
+    #[regex("unicode\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("hex\"[0-9a-fA-F_]*\"")]
+    #[regex("hex'[0-9a-fA-F_]*'")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9_]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|\*\*|<<|>>|=>|->|\+\+|--"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("abstract|anonymous|as|assembly|break|calldata|catch|constant|constructor")]
+    #[regex("continue|delete|do|else|emit|external|fallback|for|if|immutable|import")]
+    #[regex("indexed|internal|is|mapping|memory|new|override|payable|pragma|private")]
+    #[regex("public|pure|receive|return|returns|revert|storage|transient|try|type")]
+    #[regex("unchecked|using|view|virtual|while|wei|gwei|ether|seconds|minutes|hours")]
+    #[regex("days|weeks")]
+    Keyword,
+
+    #[regex("contract|enum|error|event|interface|library|struct")]
+    KeywordType,
+
+    #[regex("function|modifier")]
+    KeywordFn,
+
+    #[regex("true|false")]
+    Literal,
+
+    #[regex("u?int(8|16|24|32|40|48|56|64|72|80|88|96|104|112|120|128)?")]
+    #[regex("u?int(136|144|152|160|168|176|184|192|200|208|216|224|232|240|248|256)")]
+    #[regex("bytes([1-9]|[12][0-9]|3[0-2])?|address|bool|string|u?fixed")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^*]|\\*+[^*/])*\\*+/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Solidity {
+    const LANG: &'static str = "solidity";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Solidity::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordFn, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFn, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
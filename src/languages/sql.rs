@@ -0,0 +1,85 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Sql {
+    #[regex("[a-zA-Z_][a-zA-Z0-9_$]*")]
+    Identifier,
+
+    #[regex("\"([^\"]|\"\")*\"")]
+    #[regex("`([^`]|``)*`")]
+    #[regex("\\[[^\\]\n]*\\]")]
+    QuotedIdentifier,
+
+    #[regex("'([^']|'')*'")]
+    String,
+
+    #[regex("[0-9]+(\\.[0-9]+)?([eE][+-]?[0-9]+)?")]
+    #[regex("\\.[0-9]+")]
+    #[regex("0[xX][0-9a-fA-F]+")]
+    Number,
+
+    #[regex("[:@$?][a-zA-Z0-9_]*")]
+    Parameter,
+
+    #[regex("=|<>|!=|<|>|<=|>=|\\+|-|\\*|/|%|\\|\\||::")]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|;|\\)")]
+    Punctuation,
+
+    #[regex("(?i)add|all|alter|and|any|as|asc|begin|between|by|case|cascade|check|column")]
+    #[regex("(?i)commit|constraint|create|cross|database|default|delete|desc|distinct|drop")]
+    #[regex("(?i)else|end|except|exists|foreign|from|full|grant|group|having|if|in|index")]
+    #[regex("(?i)inner|insert|intersect|into|is|join|key|left|like|limit|not|offset|on|or")]
+    #[regex("(?i)order|outer|over|partition|primary|references|returning|revoke|right")]
+    #[regex("(?i)rollback|select|set|table|then|transaction|union|unique|update|using")]
+    #[regex("(?i)values|view|when|where|with")]
+    Keyword,
+
+    #[regex("(?i)bigint|blob|bool|boolean|char|date|datetime|decimal|double|float|int")]
+    #[regex("(?i)integer|interval|json|numeric|real|serial|smallint|text|time|timestamp")]
+    #[regex("(?i)uuid|varchar")]
+    Type,
+
+    #[regex("(?i)null|true|false")]
+    Literal,
+
+    #[regex("--[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Sql {
+    const LANG: &'static str = "sql";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Sql::*;
+
+        match tokens {
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier | QuotedIdentifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier | QuotedIdentifier, _] => Kind::Identifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Parameter, _] => Kind::SpecialIdentifier,
+            [_, Literal, _] => Kind::Literal,
+            [_, Type, _] => Kind::Type,
+            [_, Keyword, _] => Kind::Keyword,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Swift {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    #[regex("`[a-zA-Z_][a-zA-Z0-9_]*`")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\[^(\n]|\\\\\\([^)\n]*\\))*\"")]
+    #[regex(r#""""([^"]|"[^"]|""[^"])*""""#)]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+-]?[0-9_]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+")]
+    #[regex("0[oO][0-7_]+")]
+    #[regex("0[bB][01_]+")]
+    Number,
+
+    #[regex(r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|->|\.\.\.|\.\.<|\?\?"#)]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    #[regex("#[a-zA-Z_][a-zA-Z0-9_]*")]
+    Attribute,
+
+    #[regex("any|as|async|await|break|case|catch|continue|default|defer|deinit|do")]
+    #[regex("else|fallthrough|fileprivate|final|for|guard|if|import|in|init|inout")]
+    #[regex("internal|is|lazy|let|mutating|open|operator|override|private|public")]
+    #[regex("repeat|rethrows|return|self|Self|some|static|subscript|super|switch")]
+    #[regex("throw|throws|try|unowned|var|weak|where|while")]
+    Keyword,
+
+    #[regex("actor|associatedtype|class|enum|extension|protocol|struct|typealias")]
+    KeywordType,
+
+    #[token("func")]
+    KeywordFunc,
+
+    #[regex("true|false|nil")]
+    Literal,
+
+    #[regex("//[^\n]*")]
+    #[token("/*", |lex| crate::nested::comment(lex, "/*", "*/"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Swift {
+    const LANG: &'static str = "swift";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Swift::*;
+
+        match tokens {
+            [KeywordType, Identifier | StrongIdentifier, _] => Kind::SpecialIdentifier,
+            [KeywordFunc, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Attribute, _] => Kind::Glyph,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFunc, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
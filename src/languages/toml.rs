@@ -10,12 +10,16 @@ pub enum Toml {
     #[regex(r#""""([^"]|\\"]|"[^"]|""[^"])*""""#)]
     #[regex(r#"'[^'\n]*'"#)]
     #[regex(r#"'''([^']|'[^']|''[^'])*'''"#)]
+    String,
+
     #[regex("[+-]?[0-9_]+(.[0-9]+)?")]
     #[regex("[+-]?0[xX][0-9a-fA-F_]+", priority = 7)]
     #[regex("[+-]?0[oO][0-7_]+")]
     #[regex("[+-]?0[bB][01_]+")]
     #[regex("[+-]?[0-9](.[0-9]+)?[eE][+-]?[0-9]+")]
     #[regex("[+-]?(inf|nan)")]
+    Number,
+
     #[regex("(true|false)")]
     #[regex("[0-9]+-[0-9]+-[0-9]+")]
     #[regex("[0-9]+:[0-9]+:[0-9]+(.[0-9]+)?([Zz]|([+-][0-9]+:[0-9]+))?")]
@@ -38,16 +42,18 @@ impl Highlight for Toml {
     const LANG: &'static str = "toml";
     const START: Self = Self::None;
 
-    fn kind(tokens: &[Self; 2]) -> Kind {
+    fn kind(tokens: &[Self]) -> Kind {
         use Toml::*;
 
         match tokens {
             [GlyphCtx, Identifier] => Kind::StrongIdentifier,
             [Identifier, Identifier] => Kind::SpecialIdentifier,
             [_, Identifier] => Kind::Identifier,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
             [_, Literal] => Kind::Literal,
-            [_, GlyphCtx] => Kind::Glyph,
-            [_, Glyph] => Kind::Glyph,
+            [_, GlyphCtx] => Kind::Punctuation,
+            [_, Glyph] => Kind::Punctuation,
             [_, Comment] => Kind::Comment,
             _ => Kind::None,
         }
@@ -0,0 +1,96 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TypeScript {
+    #[regex("[a-z_$][a-zA-Z0-9_$]*")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_$]*")]
+    StrongIdentifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\(.|\n))*\"")]
+    #[regex("'([^'\\\\\n]|\\\\(.|\n))*'")]
+    #[regex("`([^`\\\\]|\\\\(.|\n))*`")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9]+)?n?")]
+    #[regex("0[xX][0-9a-fA-F_]+n?")]
+    #[regex("0[oO][0-7_]+n?")]
+    #[regex("0[bB][01_]+n?")]
+    Number,
+
+    #[regex(r#"\?|!|\^|-|\+|\*|&|/|%|\||~|<|>|=|=>|\?\?|\?\."#, priority = 3)]
+    Glyph,
+
+    #[token(":")]
+    Colon,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[regex(",|;|\\{|\\}|\\[|\\]|\\(|\\)")]
+    Punctuation,
+
+    #[regex("@[a-zA-Z_$][a-zA-Z0-9_$]*")]
+    Decorator,
+
+    #[regex("async|await|break|case|catch|const|continue|debugger|default")]
+    #[regex("delete|do|else|export|finally|for|from|if|import|in|instanceof")]
+    #[regex("let|new|of|return|static|super|switch|this|throw|try|typeof")]
+    #[regex("var|void|while|with|yield")]
+    #[regex("abstract|declare|infer|is|keyof|private|protected|public|readonly")]
+    #[regex("satisfies|unique")]
+    Keyword,
+
+    #[regex("class|enum|interface|module|namespace|type")]
+    KeywordCtx,
+
+    #[regex("as|extends|implements")]
+    KeywordType,
+
+    #[token("function")]
+    KeywordFunction,
+
+    #[regex("false|null|true|undefined|NaN|Infinity")]
+    Literal,
+
+    #[regex("any|bigint|boolean|never|number|object|string|symbol|unknown")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    #[regex("/\\*([^/]|[^*]/)*\\*/")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for TypeScript {
+    const LANG: &'static str = "ts";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use TypeScript::*;
+
+        match tokens {
+            [KeywordCtx, Identifier | StrongIdentifier] | [GlyphCtx, Identifier] => {
+                Kind::SpecialIdentifier
+            }
+            [KeywordFunction, Identifier | StrongIdentifier] => Kind::Function,
+            [Colon | KeywordType, StrongIdentifier] | [_, Type] => Kind::Type,
+            [_, Identifier] => Kind::Identifier,
+            [_, StrongIdentifier] => Kind::StrongIdentifier,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Literal] => Kind::Literal,
+            [_, Glyph] => Kind::Operator,
+            [_, Colon] | [_, GlyphCtx] | [_, Punctuation] => Kind::Punctuation,
+            [_, Decorator] => Kind::Glyph,
+            [_, Keyword] | [_, KeywordCtx] | [_, KeywordType] | [_, KeywordFunction] => {
+                Kind::Keyword
+            }
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
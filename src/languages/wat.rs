@@ -0,0 +1,62 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Wat {
+    #[regex("[a-z][a-z0-9_]*(\\.[a-z0-9_]+)*")]
+    Instruction,
+
+    #[regex("\\$[a-zA-Z0-9!#$%&'*+\\-./:<=>?@\\\\^_`|~]+")]
+    Identifier,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    String,
+
+    #[regex("[+-]?[0-9][0-9_]*(\\.[0-9_]*)?([eE][+-]?[0-9_]+)?", priority = 5)]
+    #[regex(
+        "[+-]?0x[0-9a-fA-F_]+(\\.[0-9a-fA-F_]*)?([pP][+-]?[0-9_]+)?",
+        priority = 5
+    )]
+    #[regex("[+-]?(inf|nan|nan:0x[0-9a-fA-F_]+)", priority = 5)]
+    Number,
+
+    #[token("(")]
+    #[token(")")]
+    Paren,
+
+    #[regex("module|func|param|result|local|global|type|import|export|memory|table")]
+    #[regex("elem|data|start|mut|offset|align|declare|item|rec|sub|final|tag")]
+    #[regex("offset=|align=")]
+    Keyword,
+
+    #[regex("i32|i64|f32|f64|v128|funcref|externref|anyref|eqref|i31ref|structref")]
+    #[regex("arrayref|exnref|nullref|nullfuncref|nullexternref|i8|i16")]
+    Type,
+
+    #[regex(";;[^\n]*")]
+    #[token("(;", |lex| crate::nested::comment(lex, "(;", ";)"))]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Wat {
+    const LANG: &'static str = "wat";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Wat::*;
+
+        match tokens {
+            [_, Instruction] => Kind::Function,
+            [_, Identifier] => Kind::Identifier,
+            [_, Type] => Kind::Type,
+            [_, String] => Kind::String,
+            [_, Number] => Kind::Number,
+            [_, Paren] => Kind::Punctuation,
+            [_, Keyword] => Kind::Keyword,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
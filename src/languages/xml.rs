@@ -0,0 +1,71 @@
+use super::html::{close, in_tag, open, Tagged};
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(extras = bool)]
+pub enum Xml {
+    #[token("<", |lex| open(lex, Xml::Open))]
+    #[token("</", |lex| open(lex, Xml::Open))]
+    #[token("<?", |lex| open(lex, Xml::Open))]
+    Open,
+
+    #[token(">", |lex| close(lex, Xml::Close))]
+    #[token("/>", |lex| close(lex, Xml::Close))]
+    #[token("?>", |lex| close(lex, Xml::Close))]
+    Close,
+
+    #[regex("[a-zA-Z_:][a-zA-Z0-9_:.-]*", |lex| in_tag(lex, Xml::Name))]
+    Name,
+
+    #[token("=", |lex| in_tag(lex, Xml::Equals))]
+    Equals,
+
+    #[regex("\"[^\"]*\"", |lex| in_tag(lex, Xml::String))]
+    #[regex("'[^']*'", |lex| in_tag(lex, Xml::String))]
+    String,
+
+    #[regex("<!\\[CDATA\\[([^\\]]|\\][^\\]]|\\]\\][^>])*\\]\\]>")]
+    Cdata,
+
+    #[regex("&[a-zA-Z_][a-zA-Z0-9_.-]*;")]
+    #[regex("&#[0-9]+;")]
+    #[regex("&#[xX][0-9a-fA-F]+;")]
+    Entity,
+
+    #[regex("<![A-Z][^>]*>")]
+    Declaration,
+
+    #[regex("<!--([^-]|-[^-]|--[^>])*-->")]
+    Comment,
+
+    /// Text outside of tags, left as it is.
+    Text,
+
+    None,
+}
+
+impl Tagged for Xml {
+    const TEXT: Self = Xml::Text;
+}
+
+impl Highlight for Xml {
+    const LANG: &'static str = "xml";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Xml::*;
+
+        match tokens {
+            [Open, Name] => Kind::Keyword,
+            [Equals, Name] | [_, String] => Kind::String,
+            [_, Name] => Kind::Identifier,
+            [_, Open | Close | Equals] => Kind::Punctuation,
+            [_, Cdata] => Kind::String,
+            [_, Entity] => Kind::Literal,
+            [_, Declaration] => Kind::Glyph,
+            [_, Comment] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Yaml {
+    #[regex(r#"[^\s#:,\[\]{}"'|>&*!?-][^\n#:,\[\]{}]*"#)]
+    #[regex(r"-[^\s#:,\[\]{}][^\n#:,\[\]{}]*")]
+    Scalar,
+
+    #[regex(r#""([^"\\]|\\(.|\n))*""#)]
+    #[regex("'([^']|'')*'")]
+    String,
+
+    #[regex("[+-]?[0-9][0-9_]*(\\.[0-9]+)?([eE][+-]?[0-9]+)?", priority = 6)]
+    #[regex("0[xX][0-9a-fA-F]+|0o[0-7]+", priority = 6)]
+    #[regex("[+-]?\\.(inf|Inf|INF)|\\.(nan|NaN|NAN)", priority = 6)]
+    Number,
+
+    #[regex("true|True|TRUE|false|False|FALSE|null|Null|NULL|~", priority = 6)]
+    Literal,
+
+    #[regex("&[^\\s,\\[\\]{}]+")]
+    Anchor,
+
+    #[regex("\\*[^\\s,\\[\\]{}]+")]
+    Alias,
+
+    #[regex("![^\\s,\\[\\]{}]*")]
+    Tag,
+
+    #[regex("[|>][+-]?[0-9]?")]
+    BlockScalar,
+
+    #[token("- ")]
+    #[token("-\n")]
+    Dash,
+
+    #[token("---", priority = 6)]
+    #[token("...")]
+    Document,
+
+    #[token(":")]
+    Colon,
+
+    #[token("?")]
+    #[regex(",|\\{|\\[")]
+    Punctuation,
+
+    #[regex("\\}|\\]")]
+    Close,
+
+    #[token("\n")]
+    Newline,
+
+    #[regex("#[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Yaml {
+    const LANG: &'static str = "yaml";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Yaml::*;
+
+        match tokens {
+            // Keys start a line, an item of a sequence or an entry of a flow mapping
+            [None | Newline | Dash | Punctuation, Scalar | String | Number | Literal, Colon] => {
+                Kind::Identifier
+            }
+            [_, Scalar | String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Anchor | Alias, _] => Kind::SpecialIdentifier,
+            [_, Tag, _] => Kind::Type,
+            [_, BlockScalar | Document, _] => Kind::Glyph,
+            [_, Dash | Colon | Punctuation | Close, _] => Kind::Punctuation,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
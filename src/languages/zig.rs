@@ -0,0 +1,94 @@
+use crate::{Highlight, Kind};
+use logos::Logos;
+
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Zig {
+    #[regex("[a-z_][a-zA-Z0-9_]*")]
+    #[regex("@\"([^\"\\\\\n]|\\\\.)*\"")]
+    Identifier,
+
+    #[regex("[A-Z][a-zA-Z0-9_]*")]
+    StrongIdentifier,
+
+    #[regex("@[a-zA-Z_][a-zA-Z0-9_]*")]
+    Builtin,
+
+    #[regex("\"([^\"\\\\\n]|\\\\.)*\"")]
+    #[regex("'([^'\\\\\n]|\\\\[^'\n]+)'")]
+    #[regex("\\\\\\\\[^\n]*")]
+    String,
+
+    #[regex("[0-9][0-9_]*(\\.[0-9_]+)?([eE][+-]?[0-9_]+)?")]
+    #[regex("0[xX][0-9a-fA-F_]+(\\.[0-9a-fA-F_]+)?([pP][+-]?[0-9_]+)?")]
+    #[regex("0o[0-7_]+")]
+    #[regex("0b[01_]+")]
+    Number,
+
+    #[regex(
+        r#"\+|-|\*|/|%|&|\||\^|<|>|=|!|~|\?|\+%|-%|\*%|\+\||\*\*|\+\+|=>|\.\.|\.\.\.|\.\*|\.\?"#
+    )]
+    Glyph,
+
+    #[token(".")]
+    GlyphCtx,
+
+    #[token("(")]
+    Paren,
+
+    #[regex(",|:|;|\\{|\\}|\\[|\\]|\\)")]
+    Punctuation,
+
+    #[regex("addrspace|align|allowzero|and|asm|async|await|break|callconv|catch")]
+    #[regex("comptime|const|continue|defer|else|errdefer|export|extern|for|if")]
+    #[regex("inline|linksection|noalias|noinline|nosuspend|or|orelse|packed|pub")]
+    #[regex("resume|return|suspend|switch|test|threadlocal|try|unreachable|usingnamespace")]
+    #[regex("var|volatile|while")]
+    Keyword,
+
+    #[regex("enum|error|opaque|struct|union")]
+    KeywordType,
+
+    #[token("fn")]
+    KeywordFn,
+
+    #[regex("true|false|null|undefined")]
+    Literal,
+
+    #[regex("[iu][0-9]+|isize|usize|c_char|c_int|c_uint|c_long|c_ulong|c_short|c_ushort")]
+    #[regex("f16|f32|f64|f80|f128|bool|void|noreturn|type|anyerror|anyopaque|anytype")]
+    #[regex("comptime_int|comptime_float")]
+    Type,
+
+    #[regex("//[^\n]*")]
+    Comment,
+
+    None,
+}
+
+impl Highlight for Zig {
+    const LANG: &'static str = "zig";
+    const START: Self = Self::None;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Zig::*;
+
+        match tokens {
+            [KeywordFn, Identifier | StrongIdentifier, _] => Kind::Function,
+            [_, Identifier, Paren] => Kind::Function,
+            [GlyphCtx, Identifier, _] => Kind::SpecialIdentifier,
+            [_, Identifier, _] => Kind::Identifier,
+            [_, StrongIdentifier, _] => Kind::StrongIdentifier,
+            [_, Builtin, _] => Kind::SpecialIdentifier,
+            [_, Type, _] => Kind::Type,
+            [_, String, _] => Kind::String,
+            [_, Number, _] => Kind::Number,
+            [_, Literal, _] => Kind::Literal,
+            [_, Glyph, _] => Kind::Operator,
+            [_, GlyphCtx | Paren | Punctuation, _] => Kind::Punctuation,
+            [_, Keyword | KeywordType | KeywordFn, _] => Kind::Keyword,
+            [_, Comment, _] => Kind::Comment,
+            _ => Kind::None,
+        }
+    }
+}
@@ -4,12 +4,66 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
-#![doc = include_str!("../README.md")]
+// The examples of the README use the preprocessor provided with `std`
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
 use logos::Logos;
-use pulldown_cmark::{CodeBlockKind, CowStr, Event, Tag, TagEnd};
+use memchr::{memchr2, memchr3};
+#[cfg(feature = "std")]
+use std::io;
 
+#[cfg(feature = "std")]
+mod annotate;
+/// Highlighting of code for terminals using ANSI escape sequences.
+pub mod ansi;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "detect")]
+mod detect;
+#[cfg(feature = "std")]
+mod error;
+#[cfg(feature = "syntect")]
+mod fallback;
+#[cfg(feature = "std")]
+mod info;
 /// Definition of syntaxes of various languages.
 pub mod languages;
+#[cfg(feature = "std")]
+mod lines;
+/// Preprocessing of mdBook books.
+#[cfg(feature = "mdbook")]
+pub mod mdbook;
+// Only used by some of the languages
+#[allow(dead_code)]
+mod nested;
+#[cfg(feature = "std")]
+mod preprocessor;
+mod stylesheet;
+mod tokens;
+/// Highlighting by tree-sitter grammars.
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
+#[cfg(feature = "std")]
+pub use config::{SyntaxConfig, Tabs};
+#[cfg(feature = "detect")]
+pub use detect::detect_language;
+#[cfg(feature = "std")]
+pub use error::SyntaxError;
+pub use languages::Language;
+#[cfg(feature = "std")]
+pub use preprocessor::{HighlightExt, SyntaxPreprocessor};
+pub use stylesheet::{dark_stylesheet, default_stylesheet, light_stylesheet};
+#[cfg(feature = "serde")]
+pub use tokens::tokens_to_json;
+pub use tokens::{tokens, Tokens};
 
 /// A type of token that can be highlighted.
 pub trait Highlight: Sized + for<'a> Logos<'a, Source = str> {
@@ -19,212 +73,527 @@ pub trait Highlight: Sized + for<'a> Logos<'a, Source = str> {
     /// The token denoting the start, before input.
     const START: Self;
 
-    /// Determine the kind of a token from the current and the previous token.
-    fn kind(tokens: &[Self; 2]) -> Kind;
+    /// Number of previous tokens passed to `kind`, at most 7 together with `AHEAD`.
+    const BEHIND: usize = 1;
+
+    /// Number of following tokens passed to `kind`, at most 7 together with `BEHIND`.
+    const AHEAD: usize = 0;
+
+    /// Determine the kind of a token from the `BEHIND` previous tokens, the
+    /// current token and the `AHEAD` following tokens, in this order.
+    ///
+    /// Tokens before the start or after the end of input, and whitespace
+    /// or other trivia in place of the current token, are `START`.
+    fn kind(tokens: &[Self]) -> Kind;
+}
+
+/// A function highlighting code into a buffer, such as `highlight::<Token>`.
+pub type HighlightFn = fn(&str, &mut String) -> fmt::Result;
+
+/// A highlighter of some language usable as a trait object, created by `erase`,
+/// such as to store highlighters of different languages together.
+pub trait Highlighter: Send + Sync {
+    /// Name of the language of this highlighter.
+    fn lang(&self) -> &'static str;
+
+    /// Highlight the code in `source` using `markup`, writing the output into `buf`.
+    fn highlight(&self, source: &str, buf: &mut dyn fmt::Write, markup: &Markup) -> fmt::Result;
+}
+
+/// Highlighter of the `Token` type, created by `erase`.
+struct Erased<Token>(PhantomData<fn() -> Token>);
+
+impl<Token> Highlighter for Erased<Token>
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    fn lang(&self) -> &'static str {
+        Token::LANG
+    }
+
+    fn highlight(&self, source: &str, buf: &mut dyn fmt::Write, markup: &Markup) -> fmt::Result {
+        highlight_with::<Token>(source, buf, markup)
+    }
+}
+
+/// Box a highlighter of the `Token` type, erasing the type.
+pub fn erase<Token>() -> Box<dyn Highlighter>
+where
+    Token: Highlight + Eq + Copy + 'static,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    Box::new(Erased::<Token>(PhantomData))
 }
 
 /// Possible kind of a token in the highlighted syntax.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Kind {
     /// Not contained in any tags.
     None,
-    /// Rendered among `u` tags.
+    /// Rendered with the `glyph` class, or among `u` tags.
     Glyph,
-    /// Rendered among `span` tags.
+    /// Rendered with the `literal` class, or among `span` tags.
     Literal,
-    /// Rendered among `var` tags.
+    /// Rendered with the `identifier` class, or among `var` tags.
     Identifier,
-    /// Rendered among `em` tags.
+    /// Rendered with the `special-identifier` class, or among `em` tags.
     SpecialIdentifier,
-    /// Rendered among `strong` tags.
+    /// Rendered with the `strong-identifier` class, or among `strong` tags.
     StrongIdentifier,
-    /// Rendered among `b` tags.
+    /// Rendered with the `keyword` class, or among `b` tags.
     Keyword,
-    /// Rendered among `i` tags.
+    /// Rendered with the `comment` class, or among `i` tags.
     Comment,
+    /// Rendered with the `number` class, or among `span` tags.
+    Number,
+    /// Rendered with the `string` class, or among `span` tags.
+    String,
+    /// Rendered with the `function` class, or among `em` tags.
+    Function,
+    /// Rendered with the `type` class, or among `strong` tags.
+    Type,
+    /// Rendered with the `operator` class, or among `u` tags.
+    Operator,
+    /// Rendered with the `punctuation` class, not contained in any tags.
+    Punctuation,
+    /// Rendered with the `inserted` class, or among `ins` tags.
+    Inserted,
+    /// Rendered with the `deleted` class, or among `del` tags.
+    Deleted,
+    /// Rendered with the `marker` class, or among `mark` tags.
+    Marker,
 }
 
-static HIGHLIGHT_CLASS: [Option<&'static str>; 8] = {
-    let mut classes = [None; 8];
-
-    classes[Kind::Glyph as usize] = Some("glyph");
-    classes[Kind::Literal as usize] = Some("literal");
-    classes[Kind::Identifier as usize] = Some("identifier");
-    classes[Kind::SpecialIdentifier as usize] = Some("special-identifier");
-    classes[Kind::StrongIdentifier as usize] = Some("strong-identifier");
-    classes[Kind::Keyword as usize] = Some("keyword");
-    classes[Kind::Comment as usize] = Some("comment");
+impl Kind {
+    /// The number of kinds.
+    const COUNT: usize = Kind::Marker as usize + 1;
 
-    classes
-};
+    /// A stable name of the kind, such as `special-identifier`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Kind::None => "none",
+            Kind::Glyph => "glyph",
+            Kind::Literal => "literal",
+            Kind::Identifier => "identifier",
+            Kind::SpecialIdentifier => "special-identifier",
+            Kind::StrongIdentifier => "strong-identifier",
+            Kind::Keyword => "keyword",
+            Kind::Comment => "comment",
+            Kind::Number => "number",
+            Kind::String => "string",
+            Kind::Function => "function",
+            Kind::Type => "type",
+            Kind::Operator => "operator",
+            Kind::Punctuation => "punctuation",
+            Kind::Inserted => "inserted",
+            Kind::Deleted => "deleted",
+            Kind::Marker => "marker",
+        }
+    }
 
-/// A preprocessor that highlights syntax in `pulldown_cmark` events.
-#[derive(Debug, Default)]
-pub struct SyntaxPreprocessor<'a, I: Iterator<Item = Event<'a>>> {
-    parent: I,
+    /// The kind of the `name` given by `Kind::name`.
+    pub fn from_name(name: &str) -> Option<Kind> {
+        Some(match name {
+            "none" => Kind::None,
+            "glyph" => Kind::Glyph,
+            "literal" => Kind::Literal,
+            "identifier" => Kind::Identifier,
+            "special-identifier" => Kind::SpecialIdentifier,
+            "strong-identifier" => Kind::StrongIdentifier,
+            "keyword" => Kind::Keyword,
+            "comment" => Kind::Comment,
+            "number" => Kind::Number,
+            "string" => Kind::String,
+            "function" => Kind::Function,
+            "type" => Kind::Type,
+            "operator" => Kind::Operator,
+            "punctuation" => Kind::Punctuation,
+            "inserted" => Kind::Inserted,
+            "deleted" => Kind::Deleted,
+            "marker" => Kind::Marker,
+            _ => return None,
+        })
+    }
 }
 
-impl<'a, I: Iterator<Item = Event<'a>>> SyntaxPreprocessor<'a, I> {
-    /// Create a new syntax preprocessor from `parent`.
-    pub fn new(parent: I) -> Self {
-        Self { parent }
+/// Kinds are serialized as their names.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Kind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
     }
 }
 
-impl<'a, I: Iterator<Item = Event<'a>>> Iterator for SyntaxPreprocessor<'a, I> {
-    type Item = Event<'a>;
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Kind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let lang = match self.parent.next()? {
-            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) if !lang.is_empty() => lang,
-            #[cfg(feature = "latex2mathml")]
-            Event::InlineMath(c) => {
-                return Some(Event::Html(
-                    latex2mathml::latex_to_mathml(
-                        c.as_ref(),
-                        latex2mathml::DisplayStyle::Inline,
-                    )
-                    .unwrap_or_else(|e| e.to_string())
-                    .into(),
-                ));
-            }
-            #[cfg(feature = "latex2mathml")]
-            Event::DisplayMath(c) => {
-                return Some(Event::Html(
-                    latex2mathml::latex_to_mathml(
-                        c.as_ref(),
-                        latex2mathml::DisplayStyle::Block,
-                    )
-                    .unwrap_or_else(|e| e.to_string())
-                    .into(),
-                ));
-            }
-            other => return Some(other),
-        };
+        impl serde::de::Visitor<'_> for KindVisitor {
+            type Value = Kind;
 
-        let next = self.parent.next();
-        let code = match next {
-            Some(Event::Text(c)) => {
-                let mut code = c;
-                loop {
-                    match self.parent.next() {
-                        Some(Event::Text(ref c)) => {
-                            code = {
-                                let mut s = code.into_string();
-                                s.push_str(c);
-                                CowStr::Boxed(s.into())
-                            }
-                        }
-                        Some(Event::End(TagEnd::CodeBlock)) | None => break,
-                        Some(e) => {
-                            return Some(Event::Text(
-                                format!("Unexpected markdown event {:#?}", e).into(),
-                            ))
-                        }
-                    }
-                }
-                code
-            }
-            Some(Event::End(TagEnd::CodeBlock)) | None => CowStr::Borrowed(""),
-            Some(e) => {
-                return Some(Event::Text(
-                    format!("Unexpected markdown event {:#?}", e).into(),
-                ))
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("the name of a kind of token")
             }
-        };
 
-        let mut html = String::with_capacity(code.len() + code.len() / 4 + 60);
-        html.push_str("<pre><code class=\"language-");
-        html.push_str(lang.as_ref());
-        html.push_str("\">");
-
-        match lang.as_ref() {
-            "c" | "cpp" | "c++" => highlight::<languages::C>(&code, &mut html),
-            "rust" | "rs" => highlight::<languages::Rust>(&code, &mut html),
-            "js" | "javascript" => highlight::<languages::JavaScript>(&code, &mut html),
-            "toml" => highlight::<languages::Toml>(&code, &mut html),
-            "sh" | "shell" | "bash" => highlight::<languages::Sh>(&code, &mut html),
-            _ => write_escaped(&mut html, &code),
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Kind, E> {
+                Kind::from_name(name).ok_or_else(|| E::unknown_variant(name, KIND_NAMES))
+            }
         }
 
-        html.push_str("</code></pre>");
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+/// The names of all of the kinds.
+#[cfg(feature = "serde")]
+const KIND_NAMES: &[&str] = &[
+    "none",
+    "glyph",
+    "literal",
+    "identifier",
+    "special-identifier",
+    "strong-identifier",
+    "keyword",
+    "comment",
+    "number",
+    "string",
+    "function",
+    "type",
+    "operator",
+    "punctuation",
+    "inserted",
+    "deleted",
+    "marker",
+];
+
+/// The way highlighted tokens are marked up in the HTML output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Markup {
+    /// Tokens are wrapped in `span` tags with the class their kind maps to.
+    Classes(TagMap),
+    /// Tokens are wrapped in the HTML tags their kind maps to.
+    Tags(TagMap),
+    /// Tokens are wrapped in `span` tags with the name of their kind
+    /// in the `data-kind` attribute.
+    DataKind,
+}
+
+impl Default for Markup {
+    fn default() -> Self {
+        Markup::Classes(TagMap::CLASSES)
+    }
+}
+
+/// Mapping of the kinds of tokens to the names used to mark them up.
+///
+/// The names are written into the output verbatim, without any escaping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TagMap([Option<&'static str>; Kind::COUNT]);
+
+impl TagMap {
+    /// The class names used by `Markup::Classes` by default.
+    pub const CLASSES: TagMap = {
+        let mut classes = [None; Kind::COUNT];
+
+        classes[Kind::Glyph as usize] = Some("glyph");
+        classes[Kind::Literal as usize] = Some("literal");
+        classes[Kind::Identifier as usize] = Some("identifier");
+        classes[Kind::SpecialIdentifier as usize] = Some("special-identifier");
+        classes[Kind::StrongIdentifier as usize] = Some("strong-identifier");
+        classes[Kind::Keyword as usize] = Some("keyword");
+        classes[Kind::Comment as usize] = Some("comment");
+        classes[Kind::Number as usize] = Some("number");
+        classes[Kind::String as usize] = Some("string");
+        classes[Kind::Function as usize] = Some("function");
+        classes[Kind::Type as usize] = Some("type");
+        classes[Kind::Operator as usize] = Some("operator");
+        classes[Kind::Punctuation as usize] = Some("punctuation");
+        classes[Kind::Inserted as usize] = Some("inserted");
+        classes[Kind::Deleted as usize] = Some("deleted");
+        classes[Kind::Marker as usize] = Some("marker");
+
+        TagMap(classes)
+    };
+
+    /// The tag names used by `Markup::Tags` by default.
+    pub const TAGS: TagMap = {
+        let mut tags = [None; Kind::COUNT];
+
+        tags[Kind::Glyph as usize] = Some("u");
+        tags[Kind::Literal as usize] = Some("span");
+        tags[Kind::Identifier as usize] = Some("var");
+        tags[Kind::SpecialIdentifier as usize] = Some("em");
+        tags[Kind::StrongIdentifier as usize] = Some("strong");
+        tags[Kind::Keyword as usize] = Some("b");
+        tags[Kind::Comment as usize] = Some("i");
+        tags[Kind::Number as usize] = Some("span");
+        tags[Kind::String as usize] = Some("span");
+        tags[Kind::Function as usize] = Some("em");
+        tags[Kind::Type as usize] = Some("strong");
+        tags[Kind::Operator as usize] = Some("u");
+        tags[Kind::Inserted as usize] = Some("ins");
+        tags[Kind::Deleted as usize] = Some("del");
+        tags[Kind::Marker as usize] = Some("mark");
+
+        TagMap(tags)
+    };
+
+    /// Map `kind` to `name`, or leave tokens of that kind unmarked if `None`.
+    pub const fn with(mut self, kind: Kind, name: Option<&'static str>) -> Self {
+        self.0[kind as usize] = name;
+        self
+    }
+
+    /// The name `kind` maps to.
+    #[inline]
+    pub fn get(&self, kind: Kind) -> Option<&'static str> {
+        self.0[kind as usize]
+    }
+}
+
+impl Markup {
+    /// Open the markup of a token of `kind`.
+    #[inline]
+    fn open<W: fmt::Write + ?Sized>(&self, kind: Kind, buf: &mut W) -> fmt::Result {
+        match self {
+            Markup::Classes(map) => match map.get(kind) {
+                Some(class) => {
+                    buf.write_str("<span class=\"")?;
+                    buf.write_str(class)?;
+                    buf.write_str("\">")
+                }
+                None => Ok(()),
+            },
+            Markup::Tags(map) => match map.get(kind) {
+                Some(tag) => {
+                    buf.write_char('<')?;
+                    buf.write_str(tag)?;
+                    buf.write_char('>')
+                }
+                None => Ok(()),
+            },
+            Markup::DataKind => match kind {
+                Kind::None => Ok(()),
+                kind => {
+                    buf.write_str("<span data-kind=\"")?;
+                    buf.write_str(kind.name())?;
+                    buf.write_str("\">")
+                }
+            },
+        }
+    }
 
-        Some(Event::Html(html.into()))
+    /// Close the markup of a token of `kind`.
+    #[inline]
+    fn close<W: fmt::Write + ?Sized>(&self, kind: Kind, buf: &mut W) -> fmt::Result {
+        match self {
+            Markup::Classes(map) => match map.get(kind) {
+                Some(_) => buf.write_str("</span>"),
+                None => Ok(()),
+            },
+            Markup::Tags(map) => match map.get(kind) {
+                Some(tag) => {
+                    buf.write_str("</")?;
+                    buf.write_str(tag)?;
+                    buf.write_char('>')
+                }
+                None => Ok(()),
+            },
+            Markup::DataKind => match kind {
+                Kind::None => Ok(()),
+                _ => buf.write_str("</span>"),
+            },
+        }
     }
 }
 
-/// Write with escaping special HTML characters
+/// Write `part` into `buf`, escaping the special HTML characters.
+///
+/// The characters `<`, `>`, `&`, `"` and `'` are replaced by `&lt;`, `&gt;`,
+/// `&amp;`, `&quot;` and `&#39;` respectively, which makes the output safe
+/// to place both into text and into quoted attribute values.
 #[inline]
-fn write_escaped(s: &mut String, part: &str) {
+pub fn write_escaped<W: fmt::Write + ?Sized>(buf: &mut W, part: &str) -> fmt::Result {
+    let bytes = part.as_bytes();
     let mut start = 0;
+    // Positions of the next special characters of either group
+    let mut next_markup = memchr3(b'<', b'>', b'&', bytes);
+    let mut next_quote = memchr2(b'"', b'\'', bytes);
 
-    for (idx, byte) in part.bytes().enumerate() {
-        let replace = match byte {
+    loop {
+        let idx = match (next_markup, next_quote) {
+            (Some(markup), Some(quote)) => markup.min(quote),
+            (Some(idx), None) | (None, Some(idx)) => idx,
+            (None, None) => break,
+        };
+        let replace = match bytes[idx] {
             b'<' => "&lt;",
             b'>' => "&gt;",
             b'&' => "&amp;",
             b'"' => "&quot;",
-            _ => continue,
+            _ => "&#39;",
         };
-        s.push_str(&part[start..idx]);
-        s.push_str(replace);
+        buf.write_str(&part[start..idx])?;
+        buf.write_str(replace)?;
 
         start = idx + 1;
+        if next_markup == Some(idx) {
+            next_markup = memchr3(b'<', b'>', b'&', &bytes[start..]).map(|i| start + i);
+        } else {
+            next_quote = memchr2(b'"', b'\'', &bytes[start..]).map(|i| start + i);
+        }
+    }
+
+    buf.write_str(&part[start..])
+}
+
+/// Escape the special HTML characters in `part`, as `write_escaped` does,
+/// borrowing it if there are none.
+pub fn escape(part: &str) -> Cow<'_, str> {
+    let bytes = part.as_bytes();
+    if memchr3(b'<', b'>', b'&', bytes).is_none() && memchr2(b'"', b'\'', bytes).is_none() {
+        return Cow::Borrowed(part);
     }
 
-    s.push_str(&part[start..]);
+    let mut escaped = String::with_capacity(part.len() + part.len() / 4);
+    push_escaped(&mut escaped, part);
+    Cow::Owned(escaped)
 }
 
-/// Highlight the code in `source`, placing the output into `buf`.
+pub(crate) fn push_escaped(s: &mut String, part: &str) {
+    // Writing into a `String` never fails
+    let _ = write_escaped(s, part);
+}
+
+/// Highlight the code in `source`, writing the output into `buf`.
 #[inline]
-pub fn highlight<'a, Token>(source: &'a str, buf: &mut String)
+pub fn highlight<Token>(source: &str, buf: &mut (impl fmt::Write + ?Sized)) -> fmt::Result
 where
     Token: Highlight + Eq + Copy,
-    <Token as Logos<'a>>::Extras: Default,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
 {
-    let mut lex = Token::lexer(source);
-    let mut open = Kind::None;
-    let mut last = 0usize;
-    let mut tokens = [Token::START; 2];
+    highlight_with::<Token>(source, buf, &Markup::default())
+}
 
-    while let Some(token) = lex.next() {
-        if tokens[1] != Token::START {
-            tokens[0] = tokens[1];
-        }
-        tokens[1] = token.unwrap_or(Token::START);
+/// Highlight the code in `source`, returning the output.
+pub fn highlight_to_string<Token>(source: &str) -> String
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    let mut buf = String::with_capacity(source.len() + source.len() / 4);
+    // Writing into a `String` never fails
+    let _ = highlight::<Token>(source, &mut buf);
+    buf
+}
 
-        let kind = Token::kind(&tokens);
+#[cfg(feature = "std")]
+/// Highlight the code in `source`, writing the output into `writer`.
+pub fn highlight_to_writer<Token>(source: &str, writer: &mut impl io::Write) -> io::Result<()>
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    let mut adapter = IoWriter {
+        inner: writer,
+        error: None,
+    };
+    highlight::<Token>(source, &mut adapter).map_err(|_| adapter.into_error())
+}
+
+/// Highlight the code in `source` using `markup`, writing the output into `buf`.
+#[inline]
+pub fn highlight_with<Token>(
+    source: &str,
+    buf: &mut (impl fmt::Write + ?Sized),
+    markup: &Markup,
+) -> fmt::Result
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    let mut tokens = tokens::<Token>(source);
+    let mut open = Kind::None;
+    let mut last = 0usize;
 
+    while let Some((kind, span)) = tokens.next_token() {
         if open != kind {
             // Close previous tag
-            if open != Kind::None {
-                buf.push_str("</span>");
-            }
+            markup.close(open, buf)?;
 
             // Include trivia
-            write_escaped(buf, &source[last..lex.span().start]);
+            write_escaped(buf, &source[last..span.start])?;
 
             // Open new tag
-            if let Some(tag) = HIGHLIGHT_CLASS[kind as usize] {
-                buf.push_str("<span class=\"");
-                buf.push_str(tag);
-                buf.push_str("\">");
-            }
+            markup.open(kind, buf)?;
 
             open = kind;
 
-            write_escaped(buf, lex.slice());
+            write_escaped(buf, &source[span.clone()])?;
         } else {
             // Include trivia
-            write_escaped(buf, &source[last..lex.span().end]);
+            write_escaped(buf, &source[last..span.end])?;
         }
 
-        last = lex.span().end;
+        last = span.end;
     }
 
     // Close tail tag
-    if open != Kind::None {
-        buf.push_str("</span>");
+    markup.close(open, buf)?;
+
+    // Include tail trivia
+    write_escaped(buf, &source[last..])
+}
+
+/// Highlight `code` of the built-in language `lang`, placing the output into `buf`.
+///
+/// Returns whether `lang` names a built-in language by `Language::from_name`,
+/// leaving `buf` untouched otherwise.
+#[inline]
+pub fn highlight_by_name(lang: &str, code: &str, buf: &mut String) -> bool {
+    highlight_by_name_with(lang, code, buf, &Markup::default())
+}
+
+/// Highlight `code` of the built-in language `lang` using `markup`,
+/// placing the output into `buf`.
+///
+/// Returns whether `lang` names a built-in language by `Language::from_name`,
+/// leaving `buf` untouched otherwise.
+pub fn highlight_by_name_with(lang: &str, code: &str, buf: &mut String, markup: &Markup) -> bool {
+    match Language::from_name(lang) {
+        Some(language) => {
+            // Writing into a `String` never fails
+            let _ = language.highlight_with(code, buf, markup);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Adapter writing formatted output into an `io::Write`.
+#[cfg(feature = "std")]
+struct IoWriter<'w, W: ?Sized> {
+    inner: &'w mut W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: ?Sized> IoWriter<'_, W> {
+    /// The error that made writing fail.
+    fn into_error(self) -> io::Error {
+        self.error
+            .unwrap_or_else(|| io::Error::other("formatting failed"))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
     }
 }
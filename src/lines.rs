@@ -0,0 +1,174 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+/// Replace the `\r\n` line endings of `code` by `\n`.
+pub(crate) fn normalize_newlines(code: &str) -> Cow<'_, str> {
+    match code.contains('\r') {
+        true => Cow::Owned(code.replace("\r\n", "\n")),
+        false => Cow::Borrowed(code),
+    }
+}
+
+/// Remove a single trailing newline of `code`, which only ends its last line.
+pub(crate) fn trim_newline(code: Cow<'_, str>) -> Cow<'_, str> {
+    match code {
+        Cow::Borrowed(code) => Cow::Borrowed(code.strip_suffix('\n').unwrap_or(code)),
+        Cow::Owned(mut code) => {
+            if code.ends_with('\n') {
+                code.pop();
+            }
+            Cow::Owned(code)
+        }
+    }
+}
+
+/// Expand the tabs in the indentation of `code`, or all of them if `all`,
+/// into spaces up to the next multiple of `width` columns.
+pub(crate) fn expand_tabs(code: Cow<'_, str>, width: usize, all: bool) -> Cow<'_, str> {
+    if !code.contains('\t') {
+        return code;
+    }
+    let width = width.max(1);
+
+    let mut expanded = String::with_capacity(code.len() + code.len() / 4);
+    let mut column = 0;
+    let mut indentation = true;
+    for c in code.chars() {
+        match c {
+            '\t' if all || indentation => {
+                let spaces = width - column % width;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            '\n' => {
+                expanded.push(c);
+                column = 0;
+                indentation = true;
+            }
+            c => {
+                expanded.push(c);
+                column += 1;
+                indentation &= c.is_whitespace();
+            }
+        }
+    }
+    Cow::Owned(expanded)
+}
+
+/// Remove the lines of Rust `code` hidden in documentation, as rustdoc does.
+///
+/// Lines starting with `# `, or consisting of `#` alone, are hidden,
+/// while a leading `##` is unescaped into `#`.
+pub(crate) fn strip_hidden(code: &str) -> Cow<'_, str> {
+    let escaped = |line: &str| line.trim_start().starts_with("##");
+    if !code
+        .split_inclusive('\n')
+        .any(|line| is_hidden(line) || escaped(line))
+    {
+        return Cow::Borrowed(code);
+    }
+
+    let mut stripped = String::with_capacity(code.len());
+    for line in code.split_inclusive('\n') {
+        if is_hidden(line) {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if escaped(line) {
+            // Drop the first `#`
+            stripped.push_str(&line[..line.len() - trimmed.len()]);
+            stripped.push_str(&trimmed[1..]);
+        } else {
+            stripped.push_str(line);
+        }
+    }
+    Cow::Owned(stripped)
+}
+
+/// Whether `line` of Rust code is hidden in documentation.
+fn is_hidden(line: &str) -> bool {
+    match line.trim_start().strip_prefix('#') {
+        Some(rest) => rest.trim().is_empty() || rest.starts_with([' ', '\t']),
+        None => false,
+    }
+}
+
+/// Wrap each line of the highlighted `html` into a `span` of the `line` class,
+/// placing the output into `buf`.
+///
+/// If `numbered`, the number of each line is placed into the `data-line`
+/// attribute. Lines in `marked` get the `highlighted` class as well.
+///
+/// Tags open at the end of a line are closed before it and reopened on the
+/// following line, so that tokens spanning several lines stay highlighted.
+/// The code is expected to have its trailing newline trimmed.
+pub(crate) fn wrap_lines(
+    html: &str,
+    buf: &mut String,
+    numbered: bool,
+    marked: &[RangeInclusive<usize>],
+) {
+    let mut open: Vec<&str> = Vec::new();
+
+    for (idx, content) in html.split('\n').enumerate() {
+        if idx > 0 {
+            buf.push('\n');
+        }
+
+        let number = idx + 1;
+        buf.push_str("<span class=\"line");
+        if marked.iter().any(|lines| lines.contains(&number)) {
+            buf.push_str(" highlighted");
+        }
+        buf.push('"');
+        if numbered {
+            buf.push_str(" data-line=\"");
+            buf.push_str(&number.to_string());
+            buf.push('"');
+        }
+        buf.push('>');
+
+        // Reopen the tags left open by the previous line
+        for tag in &open {
+            buf.push_str(tag);
+        }
+        buf.push_str(content);
+
+        // Track the tags opened and closed on this line
+        let mut rest = content;
+        while let Some(start) = rest.find('<') {
+            let end = rest[start..]
+                .find('>')
+                .map_or(rest.len(), |end| start + end + 1);
+            let tag = &rest[start..end];
+            if tag.starts_with("</") {
+                open.pop();
+            } else if !tag.ends_with("/>") {
+                open.push(tag);
+            }
+            rest = &rest[end..];
+        }
+
+        // Close the tags still open
+        for tag in open.iter().rev() {
+            buf.push_str("</");
+            buf.push_str(tag_name(tag));
+            buf.push('>');
+        }
+        buf.push_str("</span>");
+    }
+}
+
+/// The name of the element opened by `tag`.
+fn tag_name(tag: &str) -> &str {
+    tag[1..]
+        .split(|c: char| c.is_ascii_whitespace() || c == '>')
+        .next()
+        .unwrap_or_default()
+}
@@ -0,0 +1,87 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{SyntaxConfig, SyntaxPreprocessor};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde_json::Value;
+use std::io;
+
+/// Whether the output of the preprocessor is usable by the mdBook `renderer`.
+pub fn supports(renderer: &str) -> bool {
+    renderer == "html"
+}
+
+/// Read the `[context, book]` JSON that mdBook passes to preprocessors from
+/// `input`, writing the book with highlighted code blocks into `output`.
+pub fn run(input: impl io::Read, output: impl io::Write) -> serde_json::Result<()> {
+    let (_context, mut book): (Value, Value) = serde_json::from_reader(input)?;
+    let config = SyntaxConfig::new().with_hidden_lines(true);
+    if let Some(sections) = book.get_mut("sections") {
+        preprocess_sections(sections, &config);
+    }
+    serde_json::to_writer(output, &book)
+}
+
+/// Highlight the chapters among `sections` and their sub-chapters.
+fn preprocess_sections(sections: &mut Value, config: &SyntaxConfig) {
+    let Some(sections) = sections.as_array_mut() else {
+        return;
+    };
+    for chapter in sections
+        .iter_mut()
+        .filter_map(|item| item.get_mut("Chapter"))
+    {
+        if let Some(Value::String(content)) = chapter.get_mut("content") {
+            *content = preprocess_chapter(content, config);
+        }
+        if let Some(sub_items) = chapter.get_mut("sub_items") {
+            preprocess_sections(sub_items, config);
+        }
+    }
+}
+
+/// Replace the code blocks of the markdown `content` by their highlighted HTML,
+/// leaving the rest of the markdown for mdBook to render.
+pub fn preprocess_chapter(content: &str, config: &SyntaxConfig) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_HEADING_ATTRIBUTES;
+    let mut parser = Parser::new_ext(content, options).into_offset_iter();
+    let mut preprocessed = String::with_capacity(content.len() + content.len() / 2);
+    let mut last = 0;
+
+    while let Some((event, range)) = parser.next() {
+        if !matches!(event, Event::Start(Tag::CodeBlock(_))) {
+            continue;
+        }
+        let mut events = vec![event];
+        for (event, _) in parser.by_ref() {
+            let end = event == Event::End(TagEnd::CodeBlock);
+            events.push(event);
+            if end {
+                break;
+            }
+        }
+
+        let mut processed = SyntaxPreprocessor::with_config(events.into_iter(), config.clone());
+        let Some(Ok(Event::Html(html))) = processed.try_next() else {
+            continue;
+        };
+        preprocessed.push_str(&content[last..range.start]);
+        // Keep the HTML on a single line, so that it stays a single HTML block
+        // wherever the code block is nested
+        preprocessed.push_str(&html.replace('\n', "&#10;"));
+        if content[range.clone()].ends_with('\n') {
+            preprocessed.push('\n');
+        }
+        last = range.end;
+    }
+    preprocessed.push_str(&content[last..]);
+
+    preprocessed
+}
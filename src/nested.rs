@@ -0,0 +1,35 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use logos::{Lexer, Logos};
+
+/// Lex the rest of a comment just opened by `open`, up to the `close`
+/// matching it, such as `/* /* */ */` in Swift or `{- {- -} -}` in Haskell.
+///
+/// An unclosed comment runs to the end of the source.
+pub(crate) fn comment<'s, Token>(lex: &mut Lexer<'s, Token>, open: &str, close: &str)
+where
+    Token: Logos<'s, Source = str>,
+{
+    let rest = lex.remainder();
+    let mut depth = 1;
+    let mut i = 0;
+    while i < rest.len() {
+        if rest[i..].starts_with(close) {
+            i += close.len();
+            depth -= 1;
+            if depth == 0 {
+                break;
+            }
+        } else if rest[i..].starts_with(open) {
+            i += open.len();
+            depth += 1;
+        } else {
+            i += rest[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    lex.bump(i);
+}
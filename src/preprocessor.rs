@@ -0,0 +1,438 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::annotate::Annotations;
+#[cfg(feature = "syntect")]
+use crate::fallback;
+use crate::info::Info;
+use crate::{
+    highlight_by_name_with, lines, push_escaped, HighlightFn, Language, Markup, SyntaxConfig,
+    SyntaxError, Tabs,
+};
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Tag, TagEnd};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+/// A preprocessor that highlights syntax in `pulldown_cmark` events.
+///
+/// Fenced code blocks are highlighted according to the language of their
+/// info string. Indented code blocks have no language, so they are never
+/// highlighted and only get their contents escaped.
+///
+/// Code blocks containing anything other than text are passed through
+/// unchanged, see `try_next` to detect them.
+#[derive(Debug)]
+pub struct SyntaxPreprocessor<'a, I: Iterator<Item = Event<'a>>> {
+    parent: I,
+    config: SyntaxConfig,
+    pending: VecDeque<Event<'a>>,
+    /// Buffer reused for rendering the next code block, when the last one
+    /// has not been emitted from it, such as one rendered as math.
+    scratch: String,
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> SyntaxPreprocessor<'a, I> {
+    /// Create a new syntax preprocessor from `parent`.
+    pub fn new(parent: I) -> Self {
+        Self::with_config(parent, SyntaxConfig::default())
+    }
+
+    /// Create a new syntax preprocessor from `parent`, configured by `config`.
+    pub fn with_config(parent: I, config: SyntaxConfig) -> Self {
+        Self {
+            parent,
+            config,
+            pending: VecDeque::new(),
+            scratch: String::new(),
+        }
+    }
+
+    /// Set the prefix of the language class of the code element,
+    /// `language-` by default.
+    pub fn with_class_prefix(mut self, prefix: &str) -> Self {
+        self.config = self.config.with_class_prefix(prefix);
+        self
+    }
+
+    /// Set the way highlighted tokens are marked up,
+    /// with `TagMap::CLASSES` classes by default.
+    pub fn with_markup(mut self, markup: Markup) -> Self {
+        self.config = self.config.with_markup(markup);
+        self
+    }
+
+    /// Highlight code blocks of language `name` by `highlight`,
+    /// in preference to the built-in languages.
+    ///
+    /// The highlighted code should be escaped by `highlight`, for example
+    /// by passing `highlight::<Token>` for a custom `Highlight` type.
+    pub fn register_language(&mut self, name: &str, highlight: HighlightFn) {
+        self.config.register_language(name, highlight);
+    }
+
+    /// Highlight code blocks of language `name` by the tree-sitter `grammar`,
+    /// in preference to the built-in languages.
+    #[cfg(feature = "tree-sitter")]
+    pub fn register_grammar(&mut self, name: &str, grammar: crate::tree_sitter::Grammar) {
+        self.config.register_grammar(name, grammar);
+    }
+
+    /// Highlight code blocks of language `from` as if they were of language `to`.
+    ///
+    /// The aliases of the built-in languages, such as `rs` for `rust`,
+    /// are known by `Language::from_name` without adding them.
+    pub fn add_alias(&mut self, from: &str, to: &str) {
+        self.config.add_alias(from, to);
+    }
+
+    /// Highlight inline `code` according to its `#!lang ` hint, if any.
+    ///
+    /// Code whose hint does not name a known language, such as a shebang, is left as it is.
+    fn highlight_inline(&self, code: CowStr<'a>) -> Event<'a> {
+        let Some((lang, rest)) = code
+            .strip_prefix("#!")
+            .and_then(|hinted| hinted.split_once(' '))
+            .filter(|(lang, _)| !lang.is_empty())
+        else {
+            return Event::Code(code);
+        };
+
+        let lang = to_lowercase(lang);
+        if !self.knows(&lang) {
+            return Event::Code(code);
+        }
+        let mut html = String::with_capacity(rest.len() + rest.len() / 4 + 30);
+        html.push_str("<code class=\"");
+        write_class(&mut html, &self.config.class_prefix);
+        write_class(&mut html, &lang);
+        html.push_str("\">");
+        self.highlight_code(&lang, rest, &mut html);
+        html.push_str("</code>");
+
+        Event::InlineHtml(html.into())
+    }
+
+    /// Whether `lang` is a registered, aliased or built-in language.
+    fn knows(&self, lang: &str) -> bool {
+        #[cfg(feature = "tree-sitter")]
+        if self
+            .config
+            .registered(&self.config.grammars, lang)
+            .is_some()
+        {
+            return true;
+        }
+        #[cfg(feature = "syntect")]
+        if fallback::supports(self.config.resolve(lang)) {
+            return true;
+        }
+        self.config.aliases.contains_key(lang)
+            || self
+                .config
+                .registered(&self.config.languages, lang)
+                .is_some()
+            || Language::from_name(lang).is_some()
+    }
+
+    /// Highlight `code` of the language `lang`, placing the output into `buf`.
+    fn highlight_code(&self, lang: &str, code: &str, buf: &mut String) {
+        let annotations = Annotations {
+            markup: &self.config.markup,
+            markers: self.config.comment_markers,
+            links: self.config.links,
+        };
+        if annotations.is_empty() {
+            return self.highlight_tokens(lang, code, buf);
+        }
+        let mut html = String::with_capacity(code.len() + code.len() / 4);
+        self.highlight_tokens(lang, code, &mut html);
+        annotations.annotate(&html, buf);
+    }
+
+    /// Highlight the tokens of `code` of the language `lang`, placing the output into `buf`.
+    fn highlight_tokens(&self, lang: &str, code: &str, buf: &mut String) {
+        if self.config.max_size.is_some_and(|max| code.len() > max) {
+            push_escaped(buf, code);
+            return;
+        }
+        #[cfg(feature = "tree-sitter")]
+        if let Some(grammar) = self.config.registered(&self.config.grammars, lang) {
+            // Writing into a `String` never fails
+            let _ = grammar.highlight(code, buf, &self.config.markup);
+            return;
+        }
+        let highlight = self.config.registered(&self.config.languages, lang);
+        let lang = self.config.resolve(lang);
+        if let Some(highlight) = highlight {
+            // Writing into a `String` never fails
+            let _ = highlight(code, buf);
+        } else if !highlight_by_name_with(lang, code, buf, &self.config.markup) {
+            #[cfg(feature = "syntect")]
+            if fallback::supports(lang) {
+                // Writing into a `String` never fails
+                let _ = fallback::highlight(lang, code, buf, &self.config.markup);
+                return;
+            }
+            push_escaped(buf, code);
+        }
+    }
+}
+
+/// Extension of `pulldown_cmark` event iterators with syntax highlighting.
+pub trait HighlightExt<'a>: Iterator<Item = Event<'a>> + Sized {
+    /// Highlight syntax in the events, as `SyntaxPreprocessor::new` does.
+    fn highlight_syntax(self) -> SyntaxPreprocessor<'a, Self> {
+        SyntaxPreprocessor::new(self)
+    }
+
+    /// Highlight syntax in the events, configured by `config`.
+    fn highlight_syntax_with(self, config: SyntaxConfig) -> SyntaxPreprocessor<'a, Self> {
+        SyntaxPreprocessor::with_config(self, config)
+    }
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> HighlightExt<'a> for I {}
+
+impl<'a, I: Iterator<Item = Event<'a>> + Default> Default for SyntaxPreprocessor<'a, I> {
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> SyntaxPreprocessor<'a, I> {
+    /// Advance the preprocessor, like `next`, reporting code blocks that
+    /// cannot be highlighted.
+    ///
+    /// After an error, the events of the offending code block are passed
+    /// through unchanged by the following calls, as `next` does.
+    pub fn try_next(&mut self) -> Option<Result<Event<'a>, SyntaxError<'a>>> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(Ok(event));
+        }
+
+        let lang = match self.parent.next()? {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => Some(lang),
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => None,
+            Event::Code(code) if self.config.inline_code => {
+                return Some(Ok(self.highlight_inline(code)));
+            }
+            #[cfg(feature = "latex2mathml")]
+            Event::InlineMath(c) if self.config.math => {
+                return Some(Ok(Event::Html(
+                    render_math(&c, latex2mathml::DisplayStyle::Inline).into(),
+                )));
+            }
+            #[cfg(feature = "latex2mathml")]
+            Event::DisplayMath(c) if self.config.math => {
+                return Some(Ok(Event::Html(
+                    render_math(&c, latex2mathml::DisplayStyle::Block).into(),
+                )));
+            }
+            other => return Some(Ok(other)),
+        };
+
+        let mut code = CowStr::Borrowed("");
+        loop {
+            match self.parent.next() {
+                Some(Event::Text(c)) if code.is_empty() => code = c,
+                Some(Event::Text(c)) => {
+                    let mut s = code.into_string();
+                    s.push_str(&c);
+                    code = CowStr::Boxed(s.into());
+                }
+                Some(Event::End(TagEnd::CodeBlock)) | None => break,
+                Some(event) => {
+                    // Pass everything consumed so far through unchanged
+                    let kind = match lang {
+                        Some(lang) => CodeBlockKind::Fenced(lang),
+                        None => CodeBlockKind::Indented,
+                    };
+                    self.pending.push_back(Event::Start(Tag::CodeBlock(kind)));
+                    if !code.is_empty() {
+                        self.pending.push_back(Event::Text(code));
+                    }
+                    self.pending.push_back(event.clone());
+                    return Some(Err(SyntaxError::UnexpectedEvent(event)));
+                }
+            }
+        }
+
+        let code = match self.config.normalize_newlines {
+            true => lines::normalize_newlines(&code),
+            false => Cow::Borrowed(&*code),
+        };
+        let code = match self.config.tabs {
+            Tabs::Keep => code,
+            Tabs::Leading(width) => lines::expand_tabs(code, width, false),
+            Tabs::All(width) => lines::expand_tabs(code, width, true),
+        };
+
+        let mut html = std::mem::take(&mut self.scratch);
+        html.clear();
+        html.reserve(code.len() + code.len() / 4 + 60);
+        // Guess the language of bare fences, rendered as indented blocks otherwise
+        let lang = lang.and_then(|lang| match lang.is_empty() {
+            #[cfg(feature = "detect")]
+            true => crate::detect_language(&code).map(|name| CowStr::from(name.to_owned())),
+            #[cfg(not(feature = "detect"))]
+            true => None,
+            false => Some(lang),
+        });
+        let Some(lang) = lang else {
+            html.push_str(&self.config.wrapper.0);
+            push_escaped(&mut html, &lines::trim_newline(code));
+            html.push_str(&self.config.wrapper.1);
+            return Some(Ok(Self::emit(html)));
+        };
+        let info = Info::parse(&lang);
+        let lang = to_lowercase(info.lang);
+        let code = if self.config.hidden_lines && self.config.resolve(&lang) == "rust" {
+            lines::strip_hidden(&code)
+        } else {
+            Cow::Borrowed(&*code)
+        };
+        let code = lines::trim_newline(code);
+
+        #[cfg(feature = "latex2mathml")]
+        if self.config.math {
+            if let Some(&style) = self.config.math_languages.get(&*lang) {
+                self.scratch = html;
+                return Some(Ok(Event::Html(render_math(&code, style).into())));
+            }
+        }
+
+        if self.config.copy_button {
+            html.push_str("<div class=\"code-block\">");
+        }
+        if let Some(title) = info.attribute("title") {
+            html.push_str("<div class=\"code-title\">");
+            push_escaped(&mut html, title);
+            html.push_str("</div>");
+        }
+        if self.config.copy_button {
+            html.push_str("<button class=\"copy-code\" data-clipboard>Copy</button>");
+        }
+        self.open_wrapper(&mut html, &lang, &info);
+
+        if self.config.line_numbers || !info.lines.is_empty() {
+            let mut code_html = String::with_capacity(code.len() + code.len() / 4);
+            self.highlight_code(&lang, &code, &mut code_html);
+            lines::wrap_lines(&code_html, &mut html, self.config.line_numbers, &info.lines);
+        } else {
+            self.highlight_code(&lang, &code, &mut html);
+        }
+
+        html.push_str(&self.config.wrapper.1);
+        if self.config.copy_button {
+            html.push_str("</div>");
+        }
+
+        Some(Ok(Self::emit(html)))
+    }
+
+    /// Write the opening markup of the wrapper into `html`, with the class
+    /// and other attributes of the code block placed into its last tag.
+    ///
+    /// The classes are added to a `class="..."` attribute the tag already has.
+    /// A wrapper not ending with a tag is written as it is.
+    fn open_wrapper(&self, html: &mut String, lang: &str, info: &Info) {
+        let open = &self.config.wrapper.0;
+        let Some(tag) = open.strip_suffix('>') else {
+            html.push_str(open);
+            return;
+        };
+        let start = tag.rfind('<').unwrap_or(0);
+        let class = tag[start..].find(" class=\"").map(|at| start + at + 8);
+        // End of the existing value of the class, or of the tag without one
+        let end = class.map_or(tag.len(), |at| {
+            tag[at..].find('"').map_or(tag.len(), |quote| at + quote)
+        });
+        html.push_str(&tag[..end]);
+        match class {
+            Some(at) if at < end => html.push(' '),
+            Some(_) => {}
+            None => html.push_str(" class=\""),
+        }
+        write_class(html, &self.config.class_prefix);
+        write_class(html, lang);
+        for class in &info.classes {
+            html.push(' ');
+            write_class(html, class);
+        }
+        match class {
+            Some(_) => html.push_str(&tag[end..]),
+            None => html.push('"'),
+        }
+        for &(key, value) in &info.attributes {
+            if key == "title" || !is_attribute_name(key) {
+                continue;
+            }
+            html.push_str(" data-");
+            html.push_str(key);
+            html.push_str("=\"");
+            push_escaped(html, value);
+            html.push('"');
+        }
+        html.push('>');
+    }
+
+    /// Emit `html`, moving its contents into the event.
+    fn emit(html: String) -> Event<'a> {
+        Event::Html(CowStr::Boxed(html.into_boxed_str()))
+    }
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> Iterator for SyntaxPreprocessor<'a, I> {
+    type Item = Event<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.try_next()? {
+            Ok(event) => Some(event),
+            Err(_) => self.pending.pop_front(),
+        }
+    }
+}
+
+/// Render `latex` into MathML, or into an escaped `span` of the
+/// `math-error` class with the error message if it is malformed.
+#[cfg(feature = "latex2mathml")]
+fn render_math(latex: &str, style: latex2mathml::DisplayStyle) -> String {
+    latex2mathml::latex_to_mathml(latex, style).unwrap_or_else(|e| {
+        let mut html = String::from("<span class=\"math-error\">");
+        push_escaped(&mut html, &e.to_string());
+        html.push_str("</span>");
+        html
+    })
+}
+
+/// Convert `name` to ASCII lowercase, without allocating if it already is.
+#[inline]
+fn to_lowercase(name: &str) -> Cow<'_, str> {
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(name.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Write `name` as a single class token, escaping special HTML characters
+/// and dropping whitespace and control characters.
+#[inline]
+fn write_class(s: &mut String, name: &str) {
+    for part in name.split(|c: char| c.is_whitespace() || c.is_control()) {
+        push_escaped(s, part);
+    }
+}
+
+/// Check whether `name` can be safely used as the name of an attribute.
+#[inline]
+fn is_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
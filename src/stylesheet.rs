@@ -0,0 +1,22 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+
+/// CSS styling the classes of `TagMap::CLASSES` in code, the light theme.
+#[inline]
+pub const fn default_stylesheet() -> &'static str {
+    light_stylesheet()
+}
+
+/// CSS styling the classes of `TagMap::CLASSES` in code on a light background.
+pub const fn light_stylesheet() -> &'static str {
+    include_str!("themes/light.css")
+}
+
+/// CSS styling the classes of `TagMap::CLASSES` in code on a dark background.
+pub const fn dark_stylesheet() -> &'static str {
+    include_str!("themes/dark.css")
+}
@@ -0,0 +1,190 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{Highlight, Kind};
+use alloc::collections::VecDeque;
+#[cfg(feature = "serde")]
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+use logos::{Lexer, Logos};
+
+/// Capacity of the window of tokens passed to `Highlight::kind`.
+const WINDOW: usize = 8;
+
+/// Iterator over the byte ranges of the tokens of some code together with
+/// their kinds, created by `tokens`.
+///
+/// Whitespace and other trivia between the tokens are yielded as separate
+/// items of `Kind::None`, so that the ranges are contiguous and cover
+/// the whole source.
+pub struct Tokens<'s, Token: Highlight> {
+    lex: Lexer<'s, Token>,
+    /// Previous tokens, the last one at `Token::BEHIND - 1`.
+    behind: [Token; WINDOW],
+    /// Lexed tokens not yet classified, for looking ahead.
+    ahead: VecDeque<(Token, Range<usize>)>,
+    /// Number of tokens other than trivia in `ahead`.
+    significant: usize,
+    last: usize,
+    pending: Option<(Kind, Range<usize>)>,
+}
+
+/// Split the code in `source` into tokens, classified in the same way
+/// `highlight` does.
+pub fn tokens<Token>(source: &str) -> Tokens<'_, Token>
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    Tokens {
+        lex: Token::lexer(source),
+        behind: [Token::START; WINDOW],
+        ahead: VecDeque::new(),
+        significant: 0,
+        last: 0,
+        pending: None,
+    }
+}
+
+impl<'s, Token: Highlight + Eq + Copy> Tokens<'s, Token> {
+    /// Length of the window of tokens passed to `Highlight::kind`.
+    const LEN: usize = {
+        assert!(
+            Token::BEHIND + Token::AHEAD < WINDOW,
+            "the window of tokens is too large"
+        );
+        Token::BEHIND + 1 + Token::AHEAD
+    };
+
+    /// The code being split into tokens.
+    pub fn source(&self) -> &'s str {
+        self.lex.source()
+    }
+
+    /// Whether the current token and the tokens following it are lexed.
+    fn ready(&self) -> bool {
+        match self.ahead.front() {
+            Some((token, _)) => {
+                self.significant - (*token != Token::START) as usize >= Token::AHEAD
+            }
+            None => false,
+        }
+    }
+
+    /// The next token and its kind, skipping trivia.
+    pub(crate) fn next_token(&mut self) -> Option<(Kind, Range<usize>)> {
+        // Lex until the current token is followed by enough tokens to look at
+        while !self.ready() {
+            let Some(token) = self.lex.next() else {
+                break;
+            };
+            let token = token.unwrap_or(Token::START);
+            if token != Token::START {
+                self.significant += 1;
+            }
+            self.ahead.push_back((token, self.lex.span()));
+        }
+
+        let (token, span) = self.ahead.pop_front()?;
+
+        let mut window = [Token::START; WINDOW];
+        window[..Token::BEHIND].copy_from_slice(&self.behind[..Token::BEHIND]);
+        window[Token::BEHIND] = token;
+        let following = self.ahead.iter().map(|(token, _)| *token);
+        let following = following.filter(|token| *token != Token::START);
+        for (slot, token) in window[Token::BEHIND + 1..Self::LEN]
+            .iter_mut()
+            .zip(following)
+        {
+            *slot = token;
+        }
+        let kind = Token::kind(&window[..Self::LEN]);
+
+        if token != Token::START {
+            self.significant -= 1;
+            if Token::BEHIND > 0 {
+                self.behind.copy_within(1..Token::BEHIND, 0);
+                self.behind[Token::BEHIND - 1] = token;
+            }
+        }
+
+        Some((kind, span))
+    }
+}
+
+impl<'s, Token: Highlight + Eq + Copy> Iterator for Tokens<'s, Token> {
+    type Item = (Kind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.lex.source().len();
+        let (kind, span) = match self.pending.take() {
+            Some(token) => token,
+            None => match self.next_token() {
+                Some((kind, span)) if span.start > self.last => {
+                    let trivia = self.last..span.start;
+                    self.pending = Some((kind, span));
+                    (Kind::None, trivia)
+                }
+                Some(token) => token,
+                None if self.last < len => (Kind::None, self.last..len),
+                None => return None,
+            },
+        };
+        self.last = span.end;
+
+        Some((kind, span))
+    }
+}
+
+/// A token serialized as a map of its kind and text.
+#[cfg(feature = "serde")]
+struct Item<'s> {
+    kind: Kind,
+    text: &'s str,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Item<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut item = serializer.serialize_struct("Token", 2)?;
+        item.serialize_field("kind", &self.kind)?;
+        item.serialize_field("text", self.text)?;
+        item.end()
+    }
+}
+
+/// Serialize the tokens of the code in `source` into a JSON array of
+/// `{"kind": ..., "text": ...}` objects, as split by `tokens`.
+///
+/// Adjacent tokens of the same kind are merged into one object. The kinds are
+/// given by `Kind::name` and the texts together make up the code,
+/// so that it can be highlighted by other means, such as by a script in the browser.
+#[cfg(feature = "serde")]
+pub fn tokens_to_json<Token>(source: &str) -> String
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    let mut items: Vec<Item> = Vec::new();
+    let mut start = 0;
+    for (kind, span) in tokens::<Token>(source) {
+        match items.last_mut() {
+            // Merge adjacent tokens of the same kind, as `highlight` does
+            Some(last) if last.kind == kind => last.text = &source[start..span.end],
+            _ => {
+                start = span.start;
+                items.push(Item {
+                    kind,
+                    text: &source[span],
+                });
+            }
+        }
+    }
+    // Serializing kinds and strings never fails
+    serde_json::to_string(&items[..]).unwrap_or_default()
+}
@@ -0,0 +1,124 @@
+// This file is part of cmark-syntax. This program comes with ABSOLUTELY NO WARRANTY;
+// This is free software, and you are welcome to redistribute it under the
+// conditions of the GNU General Public License version 3.0.
+//
+// You should have received a copy of the GNU General Public License
+// along with cmark-syntax.  If not, see <http://www.gnu.org/licenses/>
+use crate::{write_escaped, Kind, Markup};
+use std::fmt;
+use tree_sitter_highlight::{HighlightConfiguration, HighlightEvent, Highlighter};
+
+pub use ::tree_sitter::{Language, QueryError};
+
+/// The mapping of capture names to kinds used by `Grammar::new`.
+///
+/// Captures of names not listed, such as `function.method`, get the kind
+/// of their longest listed prefix, such as `function`.
+pub const CAPTURES: &[(&str, Kind)] = &[
+    ("attribute", Kind::Glyph),
+    ("comment", Kind::Comment),
+    ("constant", Kind::Literal),
+    ("constant.numeric", Kind::Number),
+    ("constructor", Kind::StrongIdentifier),
+    ("diff.minus", Kind::Deleted),
+    ("diff.plus", Kind::Inserted),
+    ("escape", Kind::String),
+    ("function", Kind::Function),
+    ("keyword", Kind::Keyword),
+    ("label", Kind::SpecialIdentifier),
+    ("number", Kind::Number),
+    ("operator", Kind::Operator),
+    ("property", Kind::Identifier),
+    ("punctuation", Kind::Punctuation),
+    ("string", Kind::String),
+    ("type", Kind::Type),
+    ("variable", Kind::Identifier),
+    ("variable.builtin", Kind::SpecialIdentifier),
+];
+
+/// A tree-sitter grammar together with a highlight query,
+/// to be registered by `SyntaxConfig::with_grammar`.
+pub struct Grammar {
+    config: HighlightConfiguration,
+    kinds: Vec<Kind>,
+}
+
+impl Grammar {
+    /// Create a grammar of `language` highlighted by `highlights_query`,
+    /// mapping capture names to kinds by `CAPTURES`.
+    pub fn new(language: Language, name: &str, highlights_query: &str) -> Result<Self, QueryError> {
+        Self::with_captures(language, name, highlights_query, CAPTURES)
+    }
+
+    /// Create a grammar of `language` highlighted by `highlights_query`,
+    /// mapping capture names to kinds by `captures`.
+    pub fn with_captures(
+        language: Language,
+        name: &str,
+        highlights_query: &str,
+        captures: &[(&str, Kind)],
+    ) -> Result<Self, QueryError> {
+        let mut config = HighlightConfiguration::new(language, name, highlights_query, "", "")?;
+        let names: Vec<_> = captures.iter().map(|&(name, _)| name).collect();
+        config.configure(&names);
+
+        Ok(Grammar {
+            config,
+            kinds: captures.iter().map(|&(_, kind)| kind).collect(),
+        })
+    }
+
+    /// Highlight `code` using `markup`, writing the output into `buf`.
+    ///
+    /// Code tree-sitter fails to parse is written unhighlighted.
+    pub fn highlight(
+        &self,
+        code: &str,
+        buf: &mut (impl fmt::Write + ?Sized),
+        markup: &Markup,
+    ) -> fmt::Result {
+        let mut highlighter = Highlighter::new();
+        let Ok(events) = highlighter.highlight(&self.config, code.as_bytes(), None, None, |_| None)
+        else {
+            return write_escaped(buf, code);
+        };
+
+        // Kinds of the nested captures
+        let mut open = Vec::new();
+        let mut written = 0;
+
+        for event in events {
+            match event {
+                Ok(HighlightEvent::Source { start, end }) => {
+                    write_escaped(buf, &code[start..end])?;
+                    written = end;
+                }
+                Ok(HighlightEvent::HighlightStart(highlight)) => {
+                    let kind = self.kinds[highlight.0];
+                    markup.open(kind, buf)?;
+                    open.push(kind);
+                }
+                Ok(HighlightEvent::HighlightEnd) => {
+                    if let Some(kind) = open.pop() {
+                        markup.close(kind, buf)?;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        while let Some(kind) = open.pop() {
+            markup.close(kind, buf)?;
+        }
+        write_escaped(buf, &code[written..])
+    }
+}
+
+impl fmt::Debug for Grammar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Grammar")
+            .field("language", &self.config.language_name)
+            .field("kinds", &self.kinds)
+            .finish()
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "lang-rust")]
+use cmark_syntax::ansi::{self, Scheme};
+use cmark_syntax::languages::Rust;
+use cmark_syntax::Kind;
+
+#[test]
+fn keyword() {
+    let mut rendered = String::new();
+    ansi::highlight::<Rust>("let x = 1; // one", &mut rendered).unwrap();
+    assert_eq!(
+        rendered,
+        "\x1b[1mlet\x1b[0m x = \x1b[35m1\x1b[0m; \x1b[2m// one\x1b[0m"
+    );
+}
+
+#[test]
+fn custom_scheme() {
+    let scheme = Scheme::DEFAULT
+        .with(Kind::Keyword, Some("1;31"))
+        .with(Kind::Number, None);
+
+    let mut rendered = String::new();
+    ansi::highlight_with::<Rust>("let x = 1;", &mut rendered, &scheme).unwrap();
+    assert_eq!(rendered, "\x1b[1;31mlet\x1b[0m x = 1;");
+}
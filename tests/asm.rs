@@ -0,0 +1,60 @@
+#![cfg(feature = "lang-asm")]
+mod common;
+
+use cmark_syntax::languages::Asm;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn asm_highlight() {
+    let source = concat!(
+        ".globl main # entry\n",
+        "main:\n",
+        "    movq $0x10, %rax\n",
+        "loop: decq 8(%rsp)\n",
+        "    jnz loop\n",
+        "    mov dword ptr [rbx], 0ffh ; intel\n",
+    );
+    assert_eq!(
+        kinds::<Asm>(source),
+        [
+            (Kind::Glyph, ".globl"),
+            (Kind::Identifier, "main"),
+            (Kind::Comment, "# entry"),
+            (Kind::Function, "main:"),
+            (Kind::Keyword, "movq"),
+            (Kind::Number, "$0x10"),
+            (Kind::Punctuation, ","),
+            (Kind::SpecialIdentifier, "%rax"),
+            (Kind::Function, "loop:"),
+            (Kind::Keyword, "decq"),
+            (Kind::Number, "8"),
+            (Kind::Punctuation, "("),
+            (Kind::SpecialIdentifier, "%rsp"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "jnz"),
+            (Kind::Identifier, "loop"),
+            (Kind::Keyword, "mov"),
+            (Kind::Type, "dword"),
+            (Kind::Type, "ptr"),
+            (Kind::Punctuation, "["),
+            (Kind::SpecialIdentifier, "rbx"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "0ffh"),
+            (Kind::Comment, "; intel"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Asm>(source),
+        concat!(
+            "<span class=\"glyph\">.globl</span> <span class=\"identifier\">main</span> <span class=\"comment\"># entry</span>\n",
+            "<span class=\"function\">main:</span>\n",
+            "    <span class=\"keyword\">movq</span> <span class=\"number\">$0x10</span><span class=\"punctuation\">,</span> <span class=\"special-identifier\">%rax</span>\n",
+            "<span class=\"function\">loop:</span> <span class=\"keyword\">decq</span> <span class=\"number\">8</span><span class=\"punctuation\">(</span><span class=\"special-identifier\">%rsp</span><span class=\"punctuation\">)</span>\n",
+            "    <span class=\"keyword\">jnz</span> <span class=\"identifier\">loop</span>\n",
+            "    <span class=\"keyword\">mov</span> <span class=\"type\">dword</span> <span class=\"type\">ptr</span> <span class=\"punctuation\">[</span><span class=\"special-identifier\">rbx</span><span class=\"punctuation\">],</span> <span class=\"number\">0ffh</span> <span class=\"comment\">; intel</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("nasm"), Some(Language::Asm));
+}
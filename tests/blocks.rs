@@ -0,0 +1,679 @@
+#![cfg(all(
+    feature = "lang-c",
+    feature = "lang-js",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-sh",
+    feature = "lang-toml",
+    feature = "std",
+))]
+use cmark_syntax::languages::C;
+use cmark_syntax::{
+    highlight, HighlightExt, Kind, Markup, SyntaxConfig, SyntaxError, SyntaxPreprocessor, Tabs,
+    TagMap,
+};
+use pulldown_cmark::{html, CodeBlockKind, Event, Parser, Tag, TagEnd};
+
+fn render(markdown: &str) -> String {
+    let parser = Parser::new(markdown);
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    rendered
+}
+
+// Code blocks nested in list items are split into a text event per line
+#[test]
+fn split_text_events() {
+    let rendered = render("- item\n  ```rust\n  let a = 1;\n  let b = 2;\n  ```\n");
+    assert_eq!(
+        rendered,
+        concat!(
+            "<ul>\n<li>item<pre><code class=\"language-rust\">",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">a</span> ",
+            "<span class=\"operator\">=</span> <span class=\"number\">1</span>;\n",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">b</span> ",
+            "<span class=\"operator\">=</span> <span class=\"number\">2</span>;",
+            "</code></pre></li>\n</ul>\n",
+        )
+    );
+}
+
+#[test]
+fn empty_block() {
+    let rendered = render("```rust\n```\n");
+    assert_eq!(rendered, "<pre><code class=\"language-rust\"></code></pre>");
+}
+
+#[test]
+fn escaped_language() {
+    let rendered = render("```\"><script>alert(1)</script>\nx\n```\n");
+    assert!(!rendered.contains("<script>"));
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;\">x</code></pre>"
+    );
+}
+
+#[test]
+fn class_prefix() {
+    let parser = Parser::new("```toml\n```\n");
+    let processed = SyntaxPreprocessor::new(parser).with_class_prefix("lang-");
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(rendered, "<pre><code class=\"lang-toml\"></code></pre>");
+}
+
+#[test]
+fn tag_markup() {
+    let parser = Parser::new("```rust\nfn main() {} // done\n```\n");
+    let processed = SyntaxPreprocessor::new(parser).with_markup(Markup::Tags(TagMap::TAGS));
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-rust\"><b>fn</b> <em>main</em>() {} <i>// done</i></code></pre>"
+    );
+}
+
+#[test]
+fn custom_tag_map() {
+    let map = TagMap::TAGS
+        .with(Kind::Keyword, Some("kbd"))
+        .with(Kind::Function, None);
+    let parser = Parser::new("```rust\nfn main() {}\n```\n");
+    let processed = SyntaxPreprocessor::new(parser).with_markup(Markup::Tags(map));
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-rust\"><kbd>fn</kbd> main() {}</code></pre>"
+    );
+}
+
+#[test]
+fn config() {
+    let config = SyntaxConfig::new()
+        .with_class_prefix("")
+        .with_markup(Markup::Tags(TagMap::TAGS));
+    let parser = Parser::new("```rust\nfn main() {}\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"rust\"><b>fn</b> <em>main</em>() {}</code></pre>"
+    );
+}
+
+#[test]
+fn registered_language() {
+    let parser = Parser::new("```glsl\nvoid main() {}\n```\n");
+    let mut processed = SyntaxPreprocessor::new(parser);
+    processed.register_language("glsl", highlight::<C>);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-glsl\"><span class=\"type\">void</span> ",
+            "<span class=\"identifier\">main</span><span class=\"punctuation\">()</span> ",
+            "<span class=\"punctuation\">{}</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn registered_alias() {
+    let parser = Parser::new("```py\ndef\n```\n```python\ndef\n```\n");
+    let mut processed = SyntaxPreprocessor::new(parser);
+    processed.register_language("py", highlight::<C>);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-py\"><span class=\"identifier\">def</span></code></pre>",
+            "<pre><code class=\"language-python\"><span class=\"keyword\">def</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn line_numbers() {
+    let config = SyntaxConfig::new().with_line_numbers(true);
+    let parser = Parser::new("```rust\n/* two\nlines */\nlet a = 1;\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rust\">",
+            "<span class=\"line\" data-line=\"1\"><span class=\"comment\">/* two</span></span>\n",
+            "<span class=\"line\" data-line=\"2\"><span class=\"comment\">lines */</span></span>\n",
+            "<span class=\"line\" data-line=\"3\"><span class=\"keyword\">let</span> ",
+            "<span class=\"identifier\">a</span> <span class=\"operator\">=</span> ",
+            "<span class=\"number\">1</span>;</span>",
+            "</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn highlighted_lines() {
+    let parser = Parser::new("```toml {1,3-4,9}\na = 1\nb = 2\nc = 3\nd = 4\n```\n");
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-toml\">",
+            "<span class=\"line highlighted\"><span class=\"identifier\">a</span> = ",
+            "<span class=\"number\">1</span></span>\n",
+            "<span class=\"line\"><span class=\"identifier\">b</span> = ",
+            "<span class=\"number\">2</span></span>\n",
+            "<span class=\"line highlighted\"><span class=\"identifier\">c</span> = ",
+            "<span class=\"number\">3</span></span>\n",
+            "<span class=\"line highlighted\"><span class=\"identifier\">d</span> = ",
+            "<span class=\"number\">4</span></span>",
+            "</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn attributes() {
+    let rendered = render(concat!(
+        "```sh title=\"run <it>.sh\" data=x linenos='table' bad\"key=1\n",
+        "```\n"
+    ));
+    assert_eq!(
+        rendered,
+        concat!(
+            "<div class=\"code-title\">run &lt;it&gt;.sh</div>",
+            "<pre><code class=\"language-sh\" data-data=\"x\" data-linenos=\"table\">",
+            "</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn comment_markers() {
+    let config = SyntaxConfig::new().with_comment_markers(true);
+    let parser = Parser::new("```rust\n// TODO: fix, not TODOS\nlet TODO = 1;\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rust\"><span class=\"comment\">// ",
+            "<span class=\"marker\">TODO</span>: fix, not TODOS</span>\n",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">TODO</span> ",
+            "<span class=\"operator\">=</span> <span class=\"number\">1</span>;</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn links() {
+    let config = SyntaxConfig::new()
+        .with_links(true)
+        .with_comment_markers(true);
+    let parser = Parser::new(concat!(
+        "```rust\n",
+        "// NOTE: see https://example.com/a?b=1&c=\"2\". (http://x.org) http://\n",
+        "let url = \"https://example.com/<x>\";\n",
+        "```\n"
+    ));
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rust\"><span class=\"comment\">// ",
+            "<span class=\"marker\">NOTE</span>: see ",
+            "<a href=\"https://example.com/a?b=1&amp;c=\">https://example.com/a?b=1&amp;c=</a>",
+            "&quot;2&quot;. (<a href=\"http://x.org\">http://x.org</a>) http://</span>\n",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">url</span> ",
+            "<span class=\"operator\">=</span> <span class=\"string\">&quot;",
+            "<a href=\"https://example.com/\">https://example.com/</a>&lt;x&gt;&quot;</span>;",
+            "</code></pre>"
+        )
+    );
+}
+
+// Strings share their tag with numbers, so only comments are told apart by the tags
+#[test]
+fn links_tags() {
+    let source = "```rust\nf(\"http://a.org\", 1); // http://b.org\n```\n";
+    let rendered = |tags| {
+        let config = SyntaxConfig::new()
+            .with_links(true)
+            .with_markup(Markup::Tags(tags));
+        let mut rendered = String::new();
+        html::push_html(
+            &mut rendered,
+            SyntaxPreprocessor::with_config(Parser::new(source), config),
+        );
+        rendered
+    };
+    assert_eq!(
+        rendered(TagMap::TAGS),
+        concat!(
+            "<pre><code class=\"language-rust\"><var>f</var>(<span>&quot;http://a.org&quot;</span>, ",
+            "<span>1</span>); <i>// <a href=\"http://b.org\">http://b.org</a></i></code></pre>"
+        )
+    );
+    assert_eq!(
+        rendered(TagMap::TAGS.with(Kind::String, Some("q"))),
+        concat!(
+            "<pre><code class=\"language-rust\"><var>f</var>(<q>&quot;",
+            "<a href=\"http://a.org\">http://a.org</a>&quot;</q>, ",
+            "<span>1</span>); <i>// <a href=\"http://b.org\">http://b.org</a></i></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn max_size() {
+    let config = SyntaxConfig::new().with_max_size(Some(4));
+    let parser = Parser::new("```sh\nls\n```\n\n```sh\nls -l\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-sh\"><span class=\"strong-identifier\">ls</span>",
+            "</code></pre><pre><code class=\"language-sh\">ls -l</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn wrapper() {
+    let config = SyntaxConfig::new().with_wrapper("<div class=\"code\"><pre>", "</pre></div>");
+    let parser = Parser::new("```sh {1}\nls\n```\n\n    plain\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<div class=\"code\"><pre class=\"language-sh\"><span class=\"line highlighted\">",
+            "<span class=\"strong-identifier\">ls</span></span></pre></div>",
+            "<div class=\"code\"><pre>plain</pre></div>"
+        )
+    );
+}
+
+#[test]
+fn wrapper_class() {
+    let config = SyntaxConfig::new().with_wrapper("<div class=\"code\">", "</div>");
+    let parser = Parser::new("```sh {.dark}\nls\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<div class=\"code language-sh dark\">",
+            "<span class=\"strong-identifier\">ls</span></div>"
+        )
+    );
+
+    let config = SyntaxConfig::new().with_wrapper("", "");
+    let parser = Parser::new("```sh\nls\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(rendered, "<span class=\"strong-identifier\">ls</span>");
+}
+
+#[test]
+fn copy_button() {
+    let config = SyntaxConfig::new().with_copy_button(true);
+    let parser = Parser::new("```sh title=run.sh\nls\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<div class=\"code-block\"><div class=\"code-title\">run.sh</div>",
+            "<button class=\"copy-code\" data-clipboard>Copy</button>",
+            "<pre><code class=\"language-sh\"><span class=\"strong-identifier\">ls</span>",
+            "</code></pre></div>"
+        )
+    );
+}
+
+#[test]
+fn extra_classes() {
+    let rendered = render("```rust foo\n```\n");
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-rust foo\"></code></pre>"
+    );
+
+    let rendered = render("```{.toml .numberLines #id startFrom=\"2\"}\nx\n```\n");
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-toml numberLines\" data-startFrom=\"2\">",
+            "<span class=\"identifier\">x</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn escaped_apostrophe() {
+    let rendered = render("```text\nit's <ok> & \"fine\"\n```\n");
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-text\">it&#39;s &lt;ok&gt; &amp; &quot;fine&quot;</code></pre>"
+    );
+}
+
+#[test]
+fn case_insensitive_language() {
+    assert_eq!(render("```Rust\nfn\n```\n"), render("```rust\nfn\n```\n"));
+    assert_eq!(render("```RS\nfn\n```\n"), render("```rs\nfn\n```\n"));
+    assert_eq!(
+        render("```BASH\nls\n```\n"),
+        concat!(
+            "<pre><code class=\"language-bash\">",
+            "<span class=\"strong-identifier\">ls</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn alias() {
+    let parser = Parser::new("```console\nls\n```\n");
+    let mut processed = SyntaxPreprocessor::new(parser);
+    processed.add_alias("console", "sh");
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-console\">",
+            "<span class=\"strong-identifier\">ls</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn indented_block() {
+    assert_eq!(
+        render("Some code:\n\n    let x = a < b;\n    'quoted'\n"),
+        concat!(
+            "<p>Some code:</p>\n",
+            "<pre><code>let x = a &lt; b;\n&#39;quoted&#39;</code></pre>"
+        )
+    );
+}
+
+#[test]
+fn unexpected_event() {
+    let events = vec![
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced("rust".into()))),
+        Event::Text("let x = 1;\n".into()),
+        Event::Start(Tag::Emphasis),
+        Event::Text("oops".into()),
+        Event::End(TagEnd::Emphasis),
+        Event::End(TagEnd::CodeBlock),
+    ];
+
+    let processed: Vec<_> = SyntaxPreprocessor::new(events.clone().into_iter()).collect();
+    assert_eq!(processed, events);
+
+    let mut processed = SyntaxPreprocessor::new(events.clone().into_iter());
+    assert_eq!(
+        processed.try_next(),
+        Some(Err(SyntaxError::UnexpectedEvent(Event::Start(
+            Tag::Emphasis
+        ))))
+    );
+    let rest: Vec<_> = std::iter::from_fn(|| processed.try_next())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rest, events);
+}
+
+#[test]
+fn inline_code() {
+    let parser = Parser::new("`#!rs let x` and `<plain>` and `#! x`");
+    let config = SyntaxConfig::new().with_inline_code(true);
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<p><code class=\"language-rs\"><span class=\"keyword\">let</span> ",
+            "<span class=\"identifier\">x</span></code> ",
+            "and <code>&lt;plain&gt;</code> and <code>#! x</code></p>\n"
+        )
+    );
+
+    assert_eq!(render("`#!rs let x`"), "<p><code>#!rs let x</code></p>\n");
+}
+
+// A shebang is not a language hint, so its code is left as it is
+#[test]
+fn inline_shebang() {
+    let parser = Parser::new("`#!/bin/bash -e` and `#!nope x`");
+    let config = SyntaxConfig::new().with_inline_code(true);
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        "<p><code>#!/bin/bash -e</code> and <code>#!nope x</code></p>\n"
+    );
+}
+
+#[test]
+fn extension_trait() {
+    let mut rendered = String::new();
+    html::push_html(
+        &mut rendered,
+        Parser::new("```rs\nfn\n```").highlight_syntax(),
+    );
+    assert_eq!(
+        rendered,
+        "<pre><code class=\"language-rs\"><span class=\"keyword\">fn</span></code></pre>"
+    );
+
+    let config = SyntaxConfig::new().with_class_prefix("lang-");
+    let mut rendered = String::new();
+    html::push_html(
+        &mut rendered,
+        Parser::new("```rs\n```").highlight_syntax_with(config),
+    );
+    assert_eq!(rendered, "<pre><code class=\"lang-rs\"></code></pre>");
+}
+
+#[test]
+fn many_blocks() {
+    let blocks = [
+        "```rust\nfn main() {}\n```\n",
+        "```js\nlet x = \"<long string that makes the block longer>\";\n```\n",
+        "    indented <code>\n",
+        "```toml\na = 1\n```\n",
+    ];
+
+    // Each block renders the same as on its own, regardless of the previous ones
+    let rendered = render(&blocks.join("\n"));
+    let separate: Vec<_> = blocks.iter().map(|block| render(block)).collect();
+    assert_eq!(rendered, separate.concat());
+}
+
+#[test]
+fn hidden_lines() {
+    let markdown =
+        "```rs\n# fn main() {\n#\nlet x = 1;\n## not hidden\n    # indented\n#[test]\n# }\n```";
+    let config = SyntaxConfig::new()
+        .with_hidden_lines(true)
+        .with_markup(Markup::Classes(TagMap::CLASSES.with(Kind::Glyph, None)));
+    let processed = SyntaxPreprocessor::with_config(Parser::new(markdown), config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rs\"><span class=\"keyword\">let</span> ",
+            "<span class=\"identifier\">x</span> <span class=\"operator\">=</span> ",
+            "<span class=\"number\">1</span>;\n# <span class=\"identifier\">not</span> ",
+            "<span class=\"identifier\">hidden</span>\n#[test]</code></pre>"
+        )
+    );
+
+    // Other languages and the default configuration are untouched
+    assert!(render("```sh\n# comment\n```").contains("# comment"));
+    assert!(render(markdown).contains("<span class=\"function\">main</span>"));
+}
+
+#[test]
+fn doctest_flags() {
+    assert_eq!(
+        render("```rust,no_run\nfn\n```"),
+        "<pre><code class=\"language-rust no_run\"><span class=\"keyword\">fn</span></code></pre>"
+    );
+    assert_eq!(
+        render("```rust,ignore,edition2021 title=\"a.rs\"\nfn\n```"),
+        concat!(
+            "<div class=\"code-title\">a.rs</div>",
+            "<pre><code class=\"language-rust ignore edition2021\">",
+            "<span class=\"keyword\">fn</span></code></pre>"
+        )
+    );
+}
+
+#[test]
+fn data_kind_markup() {
+    let parser = Parser::new("```rs\nfn main\n```");
+    let processed = SyntaxPreprocessor::new(parser).with_markup(Markup::DataKind);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rs\"><span data-kind=\"keyword\">fn</span> ",
+            "<span data-kind=\"function\">main</span></code></pre>"
+        )
+    );
+    assert_eq!(Kind::SpecialIdentifier.name(), "special-identifier");
+}
+
+#[test]
+fn crlf() {
+    // The parser itself drops `\r`, but other sources of events may not
+    let events = || {
+        vec![
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced("sh".into()))),
+            Event::Text("ls\r\n".into()),
+            Event::Text("pwd\r\n".into()),
+            Event::End(TagEnd::CodeBlock),
+        ]
+    };
+    let render_events = |config: SyntaxConfig| {
+        let mut rendered = String::new();
+        let processed = SyntaxPreprocessor::with_config(events().into_iter(), config);
+        html::push_html(&mut rendered, processed);
+        rendered
+    };
+
+    assert_eq!(
+        render_events(SyntaxConfig::new()),
+        concat!(
+            "<pre><code class=\"language-sh\"><span class=\"strong-identifier\">ls</span>\n",
+            "<span class=\"strong-identifier\">pwd</span></code></pre>"
+        )
+    );
+    assert!(render_events(SyntaxConfig::new().with_normalized_newlines(false)).contains("\r\n"));
+}
+
+#[test]
+fn tabs() {
+    let markdown = "```text\n\tindented\ta\n\t \tmixed\nab\tc\n```";
+    let rendered = |tabs: Tabs| {
+        let mut rendered = String::new();
+        let config = SyntaxConfig::new().with_tabs(tabs);
+        html::push_html(
+            &mut rendered,
+            SyntaxPreprocessor::with_config(Parser::new(markdown), config),
+        );
+        rendered
+    };
+
+    assert_eq!(
+        rendered(Tabs::Leading(4)),
+        "<pre><code class=\"language-text\">    indented\ta\n        mixed\nab\tc</code></pre>"
+    );
+    assert_eq!(
+        rendered(Tabs::All(4)),
+        "<pre><code class=\"language-text\">    indented    a\n        mixed\nab  c</code></pre>"
+    );
+    assert_eq!(rendered(Tabs::Keep), render(markdown));
+}
+
+#[test]
+fn trailing_newline() {
+    let config = SyntaxConfig::new().with_line_numbers(true);
+    let parser = Parser::new("```toml\na = 1\n\n```");
+    let mut rendered = String::new();
+    html::push_html(
+        &mut rendered,
+        SyntaxPreprocessor::with_config(parser, config),
+    );
+
+    // Only one trailing newline is trimmed, so the empty line is kept
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-toml\"><span class=\"line\" data-line=\"1\">",
+            "<span class=\"identifier\">a</span> = <span class=\"number\">1</span></span>\n",
+            "<span class=\"line\" data-line=\"2\"></span></code></pre>"
+        )
+    );
+    // Unclosed blocks end the same way
+    assert_eq!(render("```sh\nls\n```"), render("```sh\nls\n"));
+}
+
+// Bare fences end like indented blocks, whether languages are detected or not
+#[test]
+fn bare_fence_newline() {
+    assert_eq!(
+        render("```\nx <y>\n```\n"),
+        "<pre><code>x &lt;y&gt;</code></pre>"
+    );
+    assert_eq!(render("```\nx <y>\n```\n"), render("    x <y>\n"));
+}
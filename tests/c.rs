@@ -0,0 +1,17 @@
+#![cfg(all(feature = "lang-c", feature = "std"))]
+use cmark_syntax::SyntaxPreprocessor;
+use pulldown_cmark::{html, Parser};
+
+// Test highlighting C code
+#[test]
+fn c_highlight() {
+    static C_CODE: &str = concat!("```c\n", include_str!("test.c"), "```");
+    let parser = Parser::new(C_CODE);
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    // let _ = std::fs::write("c.html", &rendered);
+    assert!(rendered.contains("<span class=\"keyword\">return</span>"));
+    assert_eq!(rendered, include_str!("c.html"));
+}
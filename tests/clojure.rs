@@ -0,0 +1,64 @@
+#![cfg(feature = "lang-clojure")]
+mod common;
+
+use cmark_syntax::languages::Clojure;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn clojure_highlight() {
+    let source = concat!(
+        "; greetings\n",
+        "(defn greet [name & {:keys [loud?]}]\n",
+        "  (let [s (str \"Hi \" name \\!)] #(println s -1/2 #{:a})))\n",
+    );
+    assert_eq!(
+        kinds::<Clojure>(source),
+        [
+            (Kind::Comment, "; greetings"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "defn"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "name"),
+            (Kind::Identifier, "&"),
+            (Kind::Punctuation, "{"),
+            (Kind::Literal, ":keys"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "loud?"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "let"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "s"),
+            (Kind::Punctuation, "("),
+            (Kind::Function, "str"),
+            (Kind::String, "\"Hi \""),
+            (Kind::Identifier, "name"),
+            (Kind::String, "\\!"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "]"),
+            (Kind::Glyph, "#("),
+            (Kind::Function, "println"),
+            (Kind::Identifier, "s"),
+            (Kind::Number, "-1/2"),
+            (Kind::Glyph, "#{"),
+            (Kind::Literal, ":a"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Clojure>(source),
+        concat!(
+            "<span class=\"comment\">; greetings</span>\n",
+            "<span class=\"punctuation\">(</span><span class=\"keyword\">defn</span> <span class=\"function\">greet</span> <span class=\"punctuation\">[</span><span class=\"identifier\">name</span> <span class=\"identifier\">&amp;</span> <span class=\"punctuation\">{</span><span class=\"literal\">:keys</span> <span class=\"punctuation\">[</span><span class=\"identifier\">loud?</span><span class=\"punctuation\">]}]</span>\n",
+            "  <span class=\"punctuation\">(</span><span class=\"keyword\">let</span> <span class=\"punctuation\">[</span><span class=\"identifier\">s</span> <span class=\"punctuation\">(</span><span class=\"function\">str</span> <span class=\"string\">&quot;Hi &quot;</span> <span class=\"identifier\">name</span> <span class=\"string\">\\!</span><span class=\"punctuation\">)]</span> <span class=\"glyph\">#(</span><span class=\"function\">println</span> <span class=\"identifier\">s</span> <span class=\"number\">-1/2</span> <span class=\"glyph\">#{</span><span class=\"literal\">:a</span><span class=\"punctuation\">})))</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("edn"), Some(Language::Clojure));
+}
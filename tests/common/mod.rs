@@ -0,0 +1,15 @@
+//! Helpers shared by the tests of the languages.
+use cmark_syntax::{tokens, Highlight, Kind};
+use logos::Logos;
+
+/// The tokens of `source` other than trivia, with their kinds.
+pub fn kinds<Token>(source: &str) -> Vec<(Kind, &str)>
+where
+    Token: Highlight + Eq + Copy,
+    for<'a> <Token as Logos<'a>>::Extras: Default,
+{
+    tokens::<Token>(source)
+        .filter(|&(kind, _)| kind != Kind::None)
+        .map(|(kind, span)| (kind, &source[span]))
+        .collect()
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "lang-cpp")]
+mod common;
+
+use cmark_syntax::languages::Cpp;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn cpp_highlight() {
+    let source = concat!(
+        "#include <vector>\n",
+        "namespace app {\n",
+        "template <typename T>\n",
+        "constexpr auto s = R\"x(a \"quoted\" )\" b)x\"; /* c */\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Cpp>(source),
+        [
+            (Kind::Keyword, "#include"),
+            (Kind::Operator, "<"),
+            (Kind::Identifier, "vector"),
+            (Kind::Operator, ">"),
+            (Kind::Keyword, "namespace"),
+            (Kind::SpecialIdentifier, "app"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "template"),
+            (Kind::Operator, "<"),
+            (Kind::Keyword, "typename"),
+            (Kind::SpecialIdentifier, "T"),
+            (Kind::Operator, ">"),
+            (Kind::Keyword, "constexpr"),
+            (Kind::Keyword, "auto"),
+            (Kind::Identifier, "s"),
+            (Kind::Operator, "="),
+            (Kind::String, "R\"x(a \"quoted\" )\" b)x\""),
+            (Kind::Comment, "/* c */"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Cpp>(source),
+        concat!(
+            "<span class=\"keyword\">#include</span> <span class=\"operator\">&lt;</span><span class=\"identifier\">vector</span><span class=\"operator\">&gt;</span>\n",
+            "<span class=\"keyword\">namespace</span> <span class=\"special-identifier\">app</span> <span class=\"punctuation\">{</span>\n",
+            "<span class=\"keyword\">template</span> <span class=\"operator\">&lt;</span><span class=\"keyword\">typename</span> <span class=\"special-identifier\">T</span><span class=\"operator\">&gt;</span>\n",
+            "<span class=\"keyword\">constexpr</span> <span class=\"keyword\">auto</span> <span class=\"identifier\">s</span> <span class=\"operator\">=</span> <span class=\"string\">R&quot;x(a &quot;quoted&quot; )&quot; b)x&quot;</span>; <span class=\"comment\">/* c */</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Cpp>("std::string s = R\"(raw)\";")[2..],
+        [
+            (Kind::SpecialIdentifier, "string"),
+            (Kind::Identifier, "s"),
+            (Kind::Operator, "="),
+            (Kind::String, "R\"(raw)\""),
+        ]
+    );
+    assert_eq!(Language::from_name("c++"), Some(Language::Cpp));
+    assert_eq!(Language::from_name("hpp"), Some(Language::Cpp));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "lang-crystal")]
+mod common;
+
+use cmark_syntax::languages::Crystal;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn crystal_highlight() {
+    let source = concat!(
+        "class Greeter # says hi\n",
+        "  def greet(name : String, times = 1_i64) : Nil\n",
+        "    @names = [] of String\n",
+        "    log(:greet, level: %w(a b))\n",
+        "    puts \"Hi #{name}!\"\n",
+        "  end\n",
+        "end\n",
+    );
+    assert_eq!(
+        kinds::<Crystal>(source),
+        [
+            (Kind::Keyword, "class"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Comment, "# says hi"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "String"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "times"),
+            (Kind::Operator, "="),
+            (Kind::Number, "1_i64"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "Nil"),
+            (Kind::SpecialIdentifier, "@names"),
+            (Kind::Operator, "="),
+            (Kind::Punctuation, "["),
+            (Kind::Punctuation, "]"),
+            (Kind::Keyword, "of"),
+            (Kind::Type, "String"),
+            (Kind::Identifier, "log"),
+            (Kind::Punctuation, "("),
+            (Kind::Literal, ":greet"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "level"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "%w(a b)"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "puts"),
+            (Kind::String, "\"Hi #{name}!\""),
+            (Kind::Keyword, "end"),
+            (Kind::Keyword, "end"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Crystal>(source),
+        concat!(
+            "<span class=\"keyword\">class</span> <span class=\"special-identifier\">Greeter</span> <span class=\"comment\"># says hi</span>\n",
+            "  <span class=\"keyword\">def</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span> <span class=\"punctuation\">:</span> <span class=\"type\">String</span><span class=\"punctuation\">,</span> <span class=\"identifier\">times</span> <span class=\"operator\">=</span> <span class=\"number\">1_i64</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">:</span> <span class=\"type\">Nil</span>\n",
+            "    <span class=\"special-identifier\">@names</span> <span class=\"operator\">=</span> <span class=\"punctuation\">[]</span> <span class=\"keyword\">of</span> <span class=\"type\">String</span>\n",
+            "    <span class=\"identifier\">log</span><span class=\"punctuation\">(</span><span class=\"literal\">:greet</span><span class=\"punctuation\">,</span> <span class=\"literal\">level</span><span class=\"punctuation\">:</span> <span class=\"string\">%w(a b)</span><span class=\"punctuation\">)</span>\n",
+            "    <span class=\"identifier\">puts</span> <span class=\"string\">&quot;Hi #{name}!&quot;</span>\n",
+            "  <span class=\"keyword\">end</span>\n",
+            "<span class=\"keyword\">end</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("cr"), Some(Language::Crystal));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "lang-css")]
+mod common;
+
+use cmark_syntax::languages::Css;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn css_highlight() {
+    let source = concat!(
+        "/* links */\n",
+        "a.nav:hover, #top { color: #fff !important; margin: 0 1.5em; }\n",
+        "@media screen { p { background: url(\"x.png\") } }\n",
+    );
+    assert_eq!(
+        kinds::<Css>(source),
+        [
+            (Kind::Comment, "/* links */"),
+            (Kind::StrongIdentifier, "a"),
+            (Kind::SpecialIdentifier, ".nav"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "hover"),
+            (Kind::Punctuation, ","),
+            (Kind::SpecialIdentifier, "#top"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "color"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "#fff"),
+            (Kind::Keyword, "!important"),
+            (Kind::Punctuation, ";"),
+            (Kind::Identifier, "margin"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "0"),
+            (Kind::Number, "1.5"),
+            (Kind::Type, "em"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::Keyword, "@media"),
+            (Kind::StrongIdentifier, "screen"),
+            (Kind::Punctuation, "{"),
+            (Kind::StrongIdentifier, "p"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "background"),
+            (Kind::Punctuation, ":"),
+            (Kind::Function, "url"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "\"x.png\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Css>(source),
+        concat!(
+            "<span class=\"comment\">/* links */</span>\n",
+            "<span class=\"strong-identifier\">a</span><span class=\"special-identifier\">.nav</span><span class=\"punctuation\">:</span><span class=\"strong-identifier\">hover</span><span class=\"punctuation\">,</span> <span class=\"special-identifier\">#top</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">color</span><span class=\"punctuation\">:</span> <span class=\"number\">#fff</span> <span class=\"keyword\">!important</span><span class=\"punctuation\">;</span> <span class=\"identifier\">margin</span><span class=\"punctuation\">:</span> <span class=\"number\">0</span> <span class=\"number\">1.5</span><span class=\"type\">em</span><span class=\"punctuation\">;</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"keyword\">@media</span> <span class=\"strong-identifier\">screen</span> <span class=\"punctuation\">{</span> <span class=\"strong-identifier\">p</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">background</span><span class=\"punctuation\">:</span> <span class=\"function\">url</span><span class=\"punctuation\">(</span><span class=\"string\">&quot;x.png&quot;</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">}</span> <span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("css"), Some(Language::Css));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+#[cfg(feature = "detect")]
+use cmark_syntax::detect_language;
+#[cfg(all(feature = "detect", feature = "lang-rust"))]
+use cmark_syntax::SyntaxPreprocessor;
+#[cfg(all(feature = "detect", feature = "lang-rust"))]
+use pulldown_cmark::{html, Parser};
+
+#[cfg(feature = "detect")]
+#[test]
+fn shebang() {
+    assert_eq!(detect_language("#!/bin/sh\necho hi\n"), Some("sh"));
+    assert_eq!(detect_language("#!/usr/bin/env bash\nls\n"), Some("sh"));
+    assert_eq!(detect_language("#!/usr/bin/env -S node\n"), Some("js"));
+    assert_eq!(
+        detect_language("#!/usr/bin/python3\nprint(1)\n"),
+        Some("python")
+    );
+}
+
+#[cfg(feature = "detect")]
+#[test]
+fn content() {
+    assert_eq!(
+        detect_language("fn main() {\n    println!(\"hi\");\n}\n"),
+        Some("rust")
+    );
+    assert_eq!(
+        detect_language("#include <stdio.h>\nint main() {}\n"),
+        Some("c")
+    );
+    assert_eq!(
+        detect_language("const f = x => x;\nconsole.log(f(1));\n"),
+        Some("js")
+    );
+    assert_eq!(detect_language("[package]\nname = \"x\"\n"), Some("toml"));
+    assert_eq!(detect_language("hello world\n"), None);
+}
+
+// Snippets found in several languages are not enough to guess one
+#[cfg(feature = "detect")]
+#[test]
+fn ambiguous() {
+    assert_eq!(detect_language("const x = 1;\n"), None);
+    assert_eq!(detect_language("let f = x => x + 1;\n"), None);
+    assert_eq!(detect_language("if (a) {\n    b = c;\n}\n"), None);
+}
+
+#[cfg(all(feature = "detect", feature = "lang-rust"))]
+#[test]
+fn bare_fence() {
+    let mut rendered = String::new();
+    let parser = Parser::new("```\nfn main() {}\n```\n\n```\nplain <text>\n```");
+    html::push_html(&mut rendered, SyntaxPreprocessor::new(parser));
+    assert_eq!(
+        rendered,
+        concat!(
+            "<pre><code class=\"language-rust\"><span class=\"keyword\">fn</span> ",
+            "<span class=\"function\">main</span><span class=\"punctuation\">()</span> ",
+            "<span class=\"punctuation\">{}</span></code></pre>",
+            "<pre><code>plain &lt;text&gt;</code></pre>"
+        )
+    );
+}
@@ -0,0 +1,18 @@
+#![cfg(all(feature = "lang-diff", feature = "std"))]
+use cmark_syntax::SyntaxPreprocessor;
+use pulldown_cmark::{html, Parser};
+
+// Test highlighting unified diffs
+#[test]
+fn diff_highlight() {
+    static PATCH: &str = concat!("```patch\n", include_str!("test.diff"), "```");
+    let parser = Parser::new(PATCH);
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    // let _ = std::fs::write("diff.html", &rendered);
+    assert!(rendered.contains("<span class=\"inserted\">+    println!"));
+    assert!(rendered.contains("<span class=\"deleted\">-    println!"));
+    assert_eq!(rendered, include_str!("diff.html"));
+}
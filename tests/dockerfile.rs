@@ -0,0 +1,48 @@
+#![cfg(feature = "lang-dockerfile")]
+mod common;
+
+use cmark_syntax::languages::Dockerfile;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn dockerfile_highlight() {
+    let source = concat!(
+        "# build stage\n",
+        "FROM ubuntu AS build\n",
+        "COPY --from=build /src /app\n",
+        "run apt-get install -y curl \\\n",
+        "    from ${HOME} # not a comment\n",
+        "ENV PATH=$PATH:/app\n",
+        "CMD [\"/app/run\"]\n",
+    );
+    assert_eq!(
+        kinds::<Dockerfile>(source),
+        [
+            (Kind::Comment, "# build stage"),
+            (Kind::Keyword, "FROM"),
+            (Kind::Keyword, "COPY"),
+            (Kind::SpecialIdentifier, "--from=build"),
+            (Kind::Keyword, "run"),
+            (Kind::Glyph, "\\\n"),
+            (Kind::Identifier, "${HOME}"),
+            (Kind::Keyword, "ENV"),
+            (Kind::Identifier, "$PATH"),
+            (Kind::Keyword, "CMD"),
+            (Kind::String, "\"/app/run\""),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Dockerfile>(source),
+        concat!(
+            "<span class=\"comment\"># build stage</span>\n",
+            "<span class=\"keyword\">FROM</span> ubuntu AS build\n",
+            "<span class=\"keyword\">COPY</span> <span class=\"special-identifier\">--from=build</span> /src /app\n",
+            "<span class=\"keyword\">run</span> apt-get install -y curl <span class=\"glyph\">\\\n",
+            "</span>    from <span class=\"identifier\">${HOME}</span> # not a comment\n",
+            "<span class=\"keyword\">ENV</span> PATH=<span class=\"identifier\">$PATH</span>:/app\n",
+            "<span class=\"keyword\">CMD</span> [<span class=\"string\">&quot;/app/run&quot;</span>]\n",
+        )
+    );
+    assert_eq!(Language::from_name("docker"), Some(Language::Dockerfile));
+}
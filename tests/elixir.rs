@@ -0,0 +1,70 @@
+#![cfg(feature = "lang-elixir")]
+mod common;
+
+use cmark_syntax::languages::Elixir;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn elixir_highlight() {
+    let source = concat!(
+        "defmodule Greeter do\n",
+        "  @moduledoc false # docs\n",
+        "  def greet(name, opts \\\\ [loud: true]) do\n",
+        "    if name =~ ~r/^a|b$/i, do: :ok, else: \"Hi #{name}\"\n",
+        "  end\n",
+        "end\n",
+    );
+    assert_eq!(
+        kinds::<Elixir>(source),
+        [
+            (Kind::Keyword, "defmodule"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Keyword, "do"),
+            (Kind::Glyph, "@moduledoc"),
+            (Kind::Literal, "false"),
+            (Kind::Comment, "# docs"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "opts"),
+            (Kind::Operator, "\\\\"),
+            (Kind::Punctuation, "["),
+            (Kind::Literal, "loud:"),
+            (Kind::Literal, "true"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "do"),
+            (Kind::Keyword, "if"),
+            (Kind::Identifier, "name"),
+            (Kind::Operator, "=~"),
+            (Kind::String, "~r/^a|b$/i"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "do:"),
+            (Kind::Literal, ":ok"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "else:"),
+            (Kind::String, "\"Hi #{name}\""),
+            (Kind::Keyword, "end"),
+            (Kind::Keyword, "end"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Elixir>(source),
+        concat!(
+            "<span class=\"keyword\">defmodule</span> <span class=\"special-identifier\">Greeter</span> <span class=\"keyword\">do</span>\n",
+            "  <span class=\"glyph\">@moduledoc</span> <span class=\"literal\">false</span> <span class=\"comment\"># docs</span>\n",
+            "  <span class=\"keyword\">def</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">,</span> <span class=\"identifier\">opts</span> <span class=\"operator\">\\\\</span> <span class=\"punctuation\">[</span><span class=\"literal\">loud:</span> <span class=\"literal\">true</span><span class=\"punctuation\">])</span> <span class=\"keyword\">do</span>\n",
+            "    <span class=\"keyword\">if</span> <span class=\"identifier\">name</span> <span class=\"operator\">=~</span> <span class=\"string\">~r/^a|b$/i</span><span class=\"punctuation\">,</span> <span class=\"literal\">do:</span> <span class=\"literal\">:ok</span><span class=\"punctuation\">,</span> <span class=\"literal\">else:</span> <span class=\"string\">&quot;Hi #{name}&quot;</span>\n",
+            "  <span class=\"keyword\">end</span>\n",
+            "<span class=\"keyword\">end</span>\n",
+        )
+    );
+    assert_ne!(
+        kinds::<Elixir>(":atom")[0].0,
+        kinds::<Elixir>("~w(a b)")[0].0
+    );
+    assert_eq!(Language::from_name("exs"), Some(Language::Elixir));
+}
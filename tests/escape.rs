@@ -0,0 +1,63 @@
+use cmark_syntax::write_escaped;
+use std::borrow::Cow;
+
+#[test]
+fn escape() {
+    let mut escaped = String::from("<p title='");
+    write_escaped(&mut escaped, "<a href=\"x\">Tom & Jerry's</a>").unwrap();
+    assert_eq!(
+        escaped,
+        "<p title='&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn escape_cow() {
+    assert!(matches!(
+        cmark_syntax::escape("plain_identifier"),
+        Cow::Borrowed("plain_identifier")
+    ));
+    assert_eq!(cmark_syntax::escape("a < b"), "a &lt; b");
+    assert!(matches!(cmark_syntax::escape("a < b"), Cow::Owned(_)));
+}
+
+/// Escaping one character at a time.
+fn escape_naive(part: &str) -> String {
+    part.chars()
+        .map(|c| match c {
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '&' => "&amp;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn escape_random() {
+    const ALPHABET: &[&str] = &[
+        "<", ">", "&", "\"", "'", "a", "Z", " ", "\n", "é", "€", "😀",
+    ];
+    // Xorshift, to generate the same inputs every time without dependencies
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+
+    for _ in 0..2000 {
+        let len = random() % 200;
+        let input: String = (0..len)
+            .map(|_| ALPHABET[random() % ALPHABET.len()])
+            .collect();
+
+        let mut escaped = String::new();
+        write_escaped(&mut escaped, &input).unwrap();
+        assert_eq!(escaped, escape_naive(&input), "input {:?}", input);
+        assert_eq!(cmark_syntax::escape(&input), escaped);
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "lang-fsharp")]
+mod common;
+
+use cmark_syntax::languages::FSharp;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn fsharp_highlight() {
+    let source = concat!(
+        "(* outer (* inner *) *)\n",
+        "let path = @\"C:\\dir\\\"\"file\"\"\" // verbatim\n",
+        "let describe x =\n",
+        "    match x with\n",
+        "    | Some n -> async { let! y = n in return \"\"\"a \"b\" c\"\"\" }\n",
+        "    | None -> (*) 2 3\n",
+    );
+    assert_eq!(
+        kinds::<FSharp>(source),
+        [
+            (Kind::Comment, "(* outer (* inner *) *)"),
+            (Kind::Keyword, "let"),
+            (Kind::Identifier, "path"),
+            (Kind::Operator, "="),
+            (Kind::String, "@\"C:\\dir\\\"\"file\"\"\""),
+            (Kind::Comment, "// verbatim"),
+            (Kind::Keyword, "let"),
+            (Kind::Identifier, "describe"),
+            (Kind::Identifier, "x"),
+            (Kind::Operator, "="),
+            (Kind::Keyword, "match"),
+            (Kind::Identifier, "x"),
+            (Kind::Keyword, "with"),
+            (Kind::Operator, "|"),
+            (Kind::StrongIdentifier, "Some"),
+            (Kind::Identifier, "n"),
+            (Kind::Operator, "->"),
+            (Kind::Identifier, "async"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "let!"),
+            (Kind::Identifier, "y"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "n"),
+            (Kind::Keyword, "in"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "\"\"\"a \"b\" c\"\"\""),
+            (Kind::Punctuation, "}"),
+            (Kind::Operator, "|"),
+            (Kind::StrongIdentifier, "None"),
+            (Kind::Operator, "->"),
+            (Kind::Operator, "(*)"),
+            (Kind::Number, "2"),
+            (Kind::Number, "3"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<FSharp>(source),
+        concat!(
+            "<span class=\"comment\">(* outer (* inner *) *)</span>\n",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">path</span> <span class=\"operator\">=</span> <span class=\"string\">@&quot;C:\\dir\\&quot;&quot;file&quot;&quot;&quot;</span> <span class=\"comment\">// verbatim</span>\n",
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">describe</span> <span class=\"identifier\">x</span> <span class=\"operator\">=</span>\n",
+            "    <span class=\"keyword\">match</span> <span class=\"identifier\">x</span> <span class=\"keyword\">with</span>\n",
+            "    <span class=\"operator\">|</span> <span class=\"strong-identifier\">Some</span> <span class=\"identifier\">n</span> <span class=\"operator\">-&gt;</span> <span class=\"identifier\">async</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">let!</span> <span class=\"identifier\">y</span> <span class=\"operator\">=</span> <span class=\"identifier\">n</span> <span class=\"keyword\">in</span> <span class=\"keyword\">return</span> <span class=\"string\">&quot;&quot;&quot;a &quot;b&quot; c&quot;&quot;&quot;</span> <span class=\"punctuation\">}</span>\n",
+            "    <span class=\"operator\">|</span> <span class=\"strong-identifier\">None</span> <span class=\"operator\">-&gt;</span> <span class=\"operator\">(*)</span> <span class=\"number\">2</span> <span class=\"number\">3</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("fs"), Some(Language::FSharp));
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "lang-glsl")]
+mod common;
+
+use cmark_syntax::languages::Glsl;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn glsl_highlight() {
+    let source = concat!(
+        "#version 330 core\n",
+        "layout(location = 0) in vec3 position;\n",
+        "uniform mat4 mvp; // model view projection\n",
+        "void main() {\n",
+        "    gl_Position = mvp * vec4(normalize(position), 1.0);\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Glsl>(source),
+        [
+            (Kind::Keyword, "#version"),
+            (Kind::Number, "330"),
+            (Kind::Identifier, "core"),
+            (Kind::Keyword, "layout"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "location"),
+            (Kind::Operator, "="),
+            (Kind::Number, "0"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "in"),
+            (Kind::Type, "vec3"),
+            (Kind::Identifier, "position"),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "uniform"),
+            (Kind::Type, "mat4"),
+            (Kind::Identifier, "mvp"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "// model view projection"),
+            (Kind::Type, "void"),
+            (Kind::Function, "main"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::SpecialIdentifier, "gl_Position"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "mvp"),
+            (Kind::Operator, "*"),
+            (Kind::Type, "vec4"),
+            (Kind::Punctuation, "("),
+            (Kind::Function, "normalize"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "position"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "1.0"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Glsl>(source),
+        concat!(
+            "<span class=\"keyword\">#version</span> <span class=\"number\">330</span> <span class=\"identifier\">core</span>\n",
+            "<span class=\"keyword\">layout</span><span class=\"punctuation\">(</span><span class=\"identifier\">location</span> <span class=\"operator\">=</span> <span class=\"number\">0</span><span class=\"punctuation\">)</span> <span class=\"keyword\">in</span> <span class=\"type\">vec3</span> <span class=\"identifier\">position</span><span class=\"punctuation\">;</span>\n",
+            "<span class=\"keyword\">uniform</span> <span class=\"type\">mat4</span> <span class=\"identifier\">mvp</span><span class=\"punctuation\">;</span> <span class=\"comment\">// model view projection</span>\n",
+            "<span class=\"type\">void</span> <span class=\"function\">main</span><span class=\"punctuation\">()</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"special-identifier\">gl_Position</span> <span class=\"operator\">=</span> <span class=\"identifier\">mvp</span> <span class=\"operator\">*</span> <span class=\"type\">vec4</span><span class=\"punctuation\">(</span><span class=\"function\">normalize</span><span class=\"punctuation\">(</span><span class=\"identifier\">position</span><span class=\"punctuation\">),</span> <span class=\"number\">1.0</span><span class=\"punctuation\">);</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("frag"), Some(Language::Glsl));
+}
+
+// Shader extensions such as `fs` and `m` are taken by other languages
+#[test]
+fn glsl_aliases() {
+    for name in ["glsl", "vert", "frag"] {
+        assert_eq!(Language::from_name(name), Some(Language::Glsl));
+    }
+    for name in ["fs", "m", "vs", "shader"] {
+        assert_ne!(Language::from_name(name), Some(Language::Glsl));
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "lang-go", feature = "std"))]
+mod common;
+
+use cmark_syntax::languages::Go;
+use cmark_syntax::{highlight_to_string, Kind, Language, SyntaxPreprocessor};
+use common::kinds;
+use pulldown_cmark::{html, Parser};
+
+#[test]
+fn go_highlight() {
+    let source = concat!(
+        "func worker(jobs <-chan int) {\n",
+        "    defer close(done) // finish\n",
+        "    var r rune = '\\n'\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Go>(source),
+        [
+            (Kind::Keyword, "func"),
+            (Kind::Function, "worker"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "jobs"),
+            (Kind::Operator, "<-"),
+            (Kind::Keyword, "chan"),
+            (Kind::Type, "int"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "defer"),
+            (Kind::Identifier, "close"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "done"),
+            (Kind::Punctuation, ")"),
+            (Kind::Comment, "// finish"),
+            (Kind::Keyword, "var"),
+            (Kind::Identifier, "r"),
+            (Kind::Type, "rune"),
+            (Kind::Operator, "="),
+            (Kind::String, "'\\n'"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Go>(source),
+        concat!(
+            "<span class=\"keyword\">func</span> <span class=\"function\">worker</span><span class=\"punctuation\">(</span><span class=\"identifier\">jobs</span> <span class=\"operator\">&lt;-</span><span class=\"keyword\">chan</span> <span class=\"type\">int</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">defer</span> <span class=\"identifier\">close</span><span class=\"punctuation\">(</span><span class=\"identifier\">done</span><span class=\"punctuation\">)</span> <span class=\"comment\">// finish</span>\n",
+            "    <span class=\"keyword\">var</span> <span class=\"identifier\">r</span> <span class=\"type\">rune</span> <span class=\"operator\">=</span> <span class=\"string\">&#39;\\n&#39;</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("golang"), Some(Language::Go));
+}
+
+// Raw strings are delimited by backticks, which fenced code blocks contain fine
+#[test]
+fn raw_string() {
+    let source = "s := `a \"raw\"\n// string`";
+    assert_eq!(
+        kinds::<Go>(source)[2],
+        (Kind::String, "`a \"raw\"\n// string`")
+    );
+
+    let parser = Parser::new("````go\ns := `x`\n````\n");
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, SyntaxPreprocessor::new(parser));
+    assert!(rendered.contains("<span class=\"string\">`x`</span>"));
+}
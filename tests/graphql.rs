@@ -0,0 +1,71 @@
+#![cfg(feature = "lang-graphql")]
+mod common;
+
+use cmark_syntax::languages::GraphQl;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn graphql_highlight() {
+    let source = concat!(
+        "\"\"\"\nA user.\n\"\"\"\n",
+        "type User { name: String! }\n",
+        "query Profile($id: ID = \"1\") { # fetch\n",
+        "  user(id: $id) { name @skip(if: true) }\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<GraphQl>(source),
+        [
+            (Kind::String, "\"\"\"\nA user.\n\"\"\""),
+            (Kind::Keyword, "type"),
+            (Kind::SpecialIdentifier, "User"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "String"),
+            (Kind::Operator, "!"),
+            (Kind::Punctuation, "}"),
+            (Kind::Keyword, "query"),
+            (Kind::SpecialIdentifier, "Profile"),
+            (Kind::Punctuation, "("),
+            (Kind::StrongIdentifier, "$id"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "ID"),
+            (Kind::Operator, "="),
+            (Kind::String, "\"1\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Comment, "# fetch"),
+            (Kind::Identifier, "user"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "id"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "$id"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "name"),
+            (Kind::Glyph, "@skip"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "if"),
+            (Kind::Punctuation, ":"),
+            (Kind::Literal, "true"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<GraphQl>(source),
+        concat!(
+            "<span class=\"string\">&quot;&quot;&quot;\n",
+            "A user.\n",
+            "&quot;&quot;&quot;</span>\n",
+            "<span class=\"keyword\">type</span> <span class=\"special-identifier\">User</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">name</span><span class=\"punctuation\">:</span> <span class=\"type\">String</span><span class=\"operator\">!</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"keyword\">query</span> <span class=\"special-identifier\">Profile</span><span class=\"punctuation\">(</span><span class=\"strong-identifier\">$id</span><span class=\"punctuation\">:</span> <span class=\"type\">ID</span> <span class=\"operator\">=</span> <span class=\"string\">&quot;1&quot;</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"comment\"># fetch</span>\n",
+            "  <span class=\"identifier\">user</span><span class=\"punctuation\">(</span><span class=\"identifier\">id</span><span class=\"punctuation\">:</span> <span class=\"strong-identifier\">$id</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">name</span> <span class=\"glyph\">@skip</span><span class=\"punctuation\">(</span><span class=\"identifier\">if</span><span class=\"punctuation\">:</span> <span class=\"literal\">true</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("gql"), Some(Language::GraphQl));
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "lang-groovy")]
+mod common;
+
+use cmark_syntax::languages::Groovy;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn groovy_highlight() {
+    let source = concat!(
+        "@CompileStatic // typed\n",
+        "class Greeter {\n",
+        "    def greet(name) {\n",
+        "        def half = name.size() / 2\n",
+        "        if (name ==~ /[A-Z]\\w+/) println \"Hi $name, ${half}!\"\n",
+        "    }\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Groovy>(source),
+        [
+            (Kind::Glyph, "@CompileStatic"),
+            (Kind::Comment, "// typed"),
+            (Kind::Keyword, "class"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "def"),
+            (Kind::Identifier, "half"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, "."),
+            (Kind::Function, "size"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "/"),
+            (Kind::Number, "2"),
+            (Kind::Keyword, "if"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Operator, "==~"),
+            (Kind::String, "/[A-Z]\\w+/"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "println"),
+            (Kind::String, "\"Hi $name, ${half}!\""),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Groovy>(source),
+        concat!(
+            "<span class=\"glyph\">@CompileStatic</span> <span class=\"comment\">// typed</span>\n",
+            "<span class=\"keyword\">class</span> <span class=\"special-identifier\">Greeter</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">def</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span>\n",
+            "        <span class=\"keyword\">def</span> <span class=\"identifier\">half</span> <span class=\"operator\">=</span> <span class=\"identifier\">name</span><span class=\"punctuation\">.</span><span class=\"function\">size</span><span class=\"punctuation\">()</span> <span class=\"operator\">/</span> <span class=\"number\">2</span>\n",
+            "        <span class=\"keyword\">if</span> <span class=\"punctuation\">(</span><span class=\"identifier\">name</span> <span class=\"operator\">==~</span> <span class=\"string\">/[A-Z]\\w+/</span><span class=\"punctuation\">)</span> <span class=\"identifier\">println</span> <span class=\"string\">&quot;Hi $name, ${half}!&quot;</span>\n",
+            "    <span class=\"punctuation\">}</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("gradle"), Some(Language::Groovy));
+}
+
+// Slashes open a slashy string only where an operand can start
+#[test]
+fn slashy_string() {
+    assert_eq!(
+        kinds::<Groovy>("\"a\" /re+/ // c"),
+        [
+            (Kind::String, "\"a\""),
+            (Kind::Operator, "/"),
+            (Kind::Identifier, "re"),
+            (Kind::Operator, "+"),
+            (Kind::Operator, "/"),
+            (Kind::Comment, "// c"),
+        ]
+    );
+    assert_eq!(
+        kinds::<Groovy>("return /re+/"),
+        [(Kind::Keyword, "return"), (Kind::String, "/re+/")]
+    );
+    assert_eq!(
+        kinds::<Groovy>("f(a, /b/) / c"),
+        [
+            (Kind::Function, "f"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "a"),
+            (Kind::Punctuation, ","),
+            (Kind::String, "/b/"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "/"),
+            (Kind::Identifier, "c"),
+        ]
+    );
+}
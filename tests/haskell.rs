@@ -0,0 +1,60 @@
+#![cfg(feature = "lang-haskell")]
+mod common;
+
+use cmark_syntax::languages::Haskell;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn haskell_highlight() {
+    let source = concat!(
+        "{- outer {- inner -} outer -}\n",
+        "data Shape = Circle Double deriving Show\n",
+        "area :: Shape -> Double -- the area\n",
+        "area (Circle r) = pi * r' where r' = r --> 'x'\n",
+    );
+    assert_eq!(
+        kinds::<Haskell>(source),
+        [
+            (Kind::Comment, "{- outer {- inner -} outer -}"),
+            (Kind::Keyword, "data"),
+            (Kind::SpecialIdentifier, "Shape"),
+            (Kind::Operator, "="),
+            (Kind::StrongIdentifier, "Circle"),
+            (Kind::StrongIdentifier, "Double"),
+            (Kind::Keyword, "deriving"),
+            (Kind::StrongIdentifier, "Show"),
+            (Kind::Function, "area"),
+            (Kind::Operator, "::"),
+            (Kind::StrongIdentifier, "Shape"),
+            (Kind::Operator, "->"),
+            (Kind::StrongIdentifier, "Double"),
+            (Kind::Comment, "-- the area"),
+            (Kind::Identifier, "area"),
+            (Kind::Punctuation, "("),
+            (Kind::StrongIdentifier, "Circle"),
+            (Kind::Identifier, "r"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "pi"),
+            (Kind::Operator, "*"),
+            (Kind::Identifier, "r'"),
+            (Kind::Keyword, "where"),
+            (Kind::Identifier, "r'"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "r"),
+            (Kind::Operator, "-->"),
+            (Kind::String, "'x'"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Haskell>(source),
+        concat!(
+            "<span class=\"comment\">{- outer {- inner -} outer -}</span>\n",
+            "<span class=\"keyword\">data</span> <span class=\"special-identifier\">Shape</span> <span class=\"operator\">=</span> <span class=\"strong-identifier\">Circle</span> <span class=\"strong-identifier\">Double</span> <span class=\"keyword\">deriving</span> <span class=\"strong-identifier\">Show</span>\n",
+            "<span class=\"function\">area</span> <span class=\"operator\">::</span> <span class=\"strong-identifier\">Shape</span> <span class=\"operator\">-&gt;</span> <span class=\"strong-identifier\">Double</span> <span class=\"comment\">-- the area</span>\n",
+            "<span class=\"identifier\">area</span> <span class=\"punctuation\">(</span><span class=\"strong-identifier\">Circle</span> <span class=\"identifier\">r</span><span class=\"punctuation\">)</span> <span class=\"operator\">=</span> <span class=\"identifier\">pi</span> <span class=\"operator\">*</span> <span class=\"identifier\">r&#39;</span> <span class=\"keyword\">where</span> <span class=\"identifier\">r&#39;</span> <span class=\"operator\">=</span> <span class=\"identifier\">r</span> <span class=\"operator\">--&gt;</span> <span class=\"string\">&#39;x&#39;</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("hs"), Some(Language::Haskell));
+}
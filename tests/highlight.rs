@@ -0,0 +1,234 @@
+#![cfg(all(
+    feature = "lang-rust",
+    feature = "lang-sh",
+    feature = "lang-toml",
+    feature = "std"
+))]
+use cmark_syntax::languages::{Rust, Toml};
+use cmark_syntax::{
+    erase, highlight, highlight_by_name, highlight_to_string, highlight_to_writer, tokens,
+    Highlight, Highlighter, Kind, Language, Markup,
+};
+use logos::Logos;
+use std::collections::HashMap;
+use std::fmt;
+
+#[test]
+fn to_string() {
+    let code = "let x = \"<x>\";";
+    let mut buf = String::new();
+    highlight::<Rust>(code, &mut buf).unwrap();
+
+    assert_eq!(highlight_to_string::<Rust>(code), buf);
+    assert_eq!(
+        buf,
+        concat!(
+            "<span class=\"keyword\">let</span> <span class=\"identifier\">x</span> ",
+            "<span class=\"operator\">=</span> <span class=\"string\">&quot;&lt;x&gt;&quot;</span>;"
+        )
+    );
+}
+
+#[test]
+fn to_writer() {
+    let code = "fn main() {}\n";
+    let mut out: Vec<u8> = Vec::new();
+    highlight_to_writer::<Rust>(code, &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        highlight_to_string::<Rust>(code)
+    );
+}
+
+/// Collects the written fragments separately.
+#[derive(Default)]
+struct Fragments(Vec<String>);
+
+impl fmt::Write for Fragments {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.push(s.to_owned());
+        Ok(())
+    }
+}
+
+#[test]
+fn to_fmt_writer() {
+    let code = "x < 1";
+    let mut fragments = Fragments::default();
+    highlight::<Rust>(code, &mut fragments).unwrap();
+
+    assert!(fragments.0.contains(&"&lt;".to_owned()));
+    assert_eq!(fragments.0.concat(), highlight_to_string::<Rust>(code));
+}
+
+#[test]
+fn token_stream() {
+    let source = "fn main() {}";
+    let tokens: Vec<_> = tokens::<Rust>(source)
+        .map(|(kind, span)| (kind, &source[span]))
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            (Kind::Keyword, "fn"),
+            (Kind::None, " "),
+            (Kind::Function, "main"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::None, " "),
+            (Kind::Punctuation, "{"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+}
+
+#[test]
+fn token_spans() {
+    let source = include_str!("rust.rs");
+    let mut end = 0;
+    for (_, span) in tokens::<Rust>(source) {
+        assert_eq!(span.start, end);
+        assert!(span.end > span.start);
+        end = span.end;
+    }
+    assert_eq!(end, source.len());
+
+    let mut tokens = tokens::<Rust>("  x  ");
+    assert_eq!(tokens.source(), "  x  ");
+    let spans: Vec<_> = tokens.by_ref().map(|(_, span)| span).collect();
+    assert_eq!(spans, [0..1, 1..2, 2..3, 3..4, 4..5]);
+}
+
+/// Calls distinguished from variables by looking ahead.
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+enum Calls {
+    #[regex("[a-z]+")]
+    Word,
+
+    #[token("(")]
+    Open,
+
+    #[token(")")]
+    Close,
+
+    None,
+}
+
+impl Highlight for Calls {
+    const LANG: &'static str = "calls";
+    const START: Self = Self::None;
+    const BEHIND: usize = 2;
+    const AHEAD: usize = 1;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        use Calls::*;
+
+        match tokens {
+            [_, _, Word, Open] => Kind::Function,
+            [Word, Open, Word, _] => Kind::SpecialIdentifier,
+            [_, _, Word, _] => Kind::Identifier,
+            _ => Kind::None,
+        }
+    }
+}
+
+#[test]
+fn lookahead() {
+    let source = "f (x) y";
+    let kinds: Vec<_> = tokens::<Calls>(source)
+        .map(|(kind, span)| (kind, &source[span]))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            (Kind::Function, "f"),
+            (Kind::None, " "),
+            (Kind::None, "("),
+            (Kind::SpecialIdentifier, "x"),
+            (Kind::None, ")"),
+            (Kind::None, " "),
+            (Kind::Identifier, "y"),
+        ]
+    );
+}
+
+#[test]
+fn erased() {
+    let highlighters: HashMap<&str, Box<dyn Highlighter>> = [erase::<Rust>(), erase::<Toml>()]
+        .into_iter()
+        .map(|highlighter| (highlighter.lang(), highlighter))
+        .collect();
+
+    for (lang, code) in [("rust", "let x = 1;"), ("toml", "x = 1")] {
+        let mut buf = String::new();
+        highlighters[lang]
+            .highlight(code, &mut buf, &Markup::default())
+            .unwrap();
+        let expected = match lang {
+            "rust" => highlight_to_string::<Rust>(code),
+            _ => highlight_to_string::<Toml>(code),
+        };
+        assert_eq!(buf, expected);
+    }
+}
+
+#[test]
+fn by_name() {
+    let mut buf = String::new();
+    assert!(highlight_by_name("rust", "let x = 1;", &mut buf));
+    assert_eq!(buf, highlight_to_string::<Rust>("let x = 1;"));
+
+    let mut buf = String::new();
+    assert!(!highlight_by_name("unknown", "let x = 1;", &mut buf));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn language() {
+    assert_eq!(Language::from_name("bash"), Some(Language::Sh));
+    assert_eq!(
+        Language::from_name("rust").map(|lang| lang.name()),
+        Some("rust")
+    );
+    assert_eq!(Language::from_name("unknown"), None);
+
+    let mut buf = String::new();
+    Language::Rust.highlight("let x = 1;", &mut buf).unwrap();
+    assert_eq!(buf, highlight_to_string::<Rust>("let x = 1;"));
+
+    let mut buf = String::new();
+    assert!(highlight_by_name("rs", "let x = 1;", &mut buf));
+    assert_eq!(buf, highlight_to_string::<Rust>("let x = 1;"));
+}
+
+/// Words, skipping the spaces between them.
+#[derive(Logos, PartialEq, Eq, Clone, Copy, Debug)]
+#[logos(skip " +")]
+enum Words {
+    #[regex("[a-z]+")]
+    Word,
+
+    None,
+}
+
+impl Highlight for Words {
+    const LANG: &'static str = "words";
+    const START: Self = Self::None;
+
+    fn kind(tokens: &[Self]) -> Kind {
+        match tokens {
+            [_, Words::Word] => Kind::Identifier,
+            _ => Kind::None,
+        }
+    }
+}
+
+// Trivia skipped by the lexer is kept after the last token, as between tokens
+#[test]
+fn tail_trivia() {
+    assert_eq!(
+        highlight_to_string::<Words>("a b  "),
+        "<span class=\"identifier\">a b</span>  "
+    );
+}
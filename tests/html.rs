@@ -0,0 +1,84 @@
+#![cfg(all(feature = "lang-html", feature = "std"))]
+mod common;
+
+use cmark_syntax::languages::Html;
+use cmark_syntax::{highlight_to_string, Kind, Language, SyntaxPreprocessor};
+use common::kinds;
+use pulldown_cmark::{html, Parser};
+
+#[test]
+fn html_highlight() {
+    let source = concat!(
+        "<!DOCTYPE html>\n",
+        "<!-- a <b> comment -->\n",
+        "<p id=intro hidden>a &lt; b = c > d</p>\n",
+    );
+    assert_eq!(
+        kinds::<Html>(source),
+        [
+            (Kind::Glyph, "<!DOCTYPE html>"),
+            (Kind::Comment, "<!-- a <b> comment -->"),
+            (Kind::Punctuation, "<"),
+            (Kind::Keyword, "p"),
+            (Kind::Identifier, "id"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "intro"),
+            (Kind::Identifier, "hidden"),
+            (Kind::Punctuation, ">"),
+            (Kind::Literal, "&lt;"),
+            (Kind::Punctuation, "</"),
+            (Kind::Keyword, "p"),
+            (Kind::Punctuation, ">"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Html>(source),
+        concat!(
+            "<span class=\"glyph\">&lt;!DOCTYPE html&gt;</span>\n",
+            "<span class=\"comment\">&lt;!-- a &lt;b&gt; comment --&gt;</span>\n",
+            "<span class=\"punctuation\">&lt;</span><span class=\"keyword\">p</span> <span class=\"identifier\">id</span><span class=\"punctuation\">=</span><span class=\"string\">intro</span> <span class=\"identifier\">hidden</span><span class=\"punctuation\">&gt;</span>a <span class=\"literal\">&amp;lt;</span> b = c &gt; d<span class=\"punctuation\">&lt;/</span><span class=\"keyword\">p</span><span class=\"punctuation\">&gt;</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("htm"), Some(Language::Html));
+}
+
+#[test]
+fn html_escaping() {
+    assert_eq!(
+        highlight_to_string::<Html>("<div class=\"x\">"),
+        concat!(
+            r#"<span class="punctuation">&lt;</span><span class="keyword">div</span> "#,
+            r#"<span class="identifier">class</span><span class="punctuation">=</span>"#,
+            r#"<span class="string">&quot;x&quot;</span><span class="punctuation">&gt;</span>"#,
+        )
+    );
+}
+
+#[test]
+fn html_text() {
+    assert_eq!(highlight_to_string::<Html>("x"), "x");
+    assert_eq!(
+        highlight_to_string::<Html>("a <b>bold</b> c"),
+        concat!(
+            r#"a <span class="punctuation">&lt;</span><span class="keyword">b</span>"#,
+            r#"<span class="punctuation">&gt;</span>bold<span class="punctuation">&lt;/</span>"#,
+            r#"<span class="keyword">b</span><span class="punctuation">&gt;</span> c"#,
+        )
+    );
+}
+
+#[test]
+fn html_block_text() {
+    let parser = Parser::new("```html\n<p>hello</p> goodbye\n```");
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, SyntaxPreprocessor::new(parser));
+    assert_eq!(
+        rendered,
+        concat!(
+            r#"<pre><code class="language-html"><span class="punctuation">&lt;</span>"#,
+            r#"<span class="keyword">p</span><span class="punctuation">&gt;</span>hello"#,
+            r#"<span class="punctuation">&lt;/</span><span class="keyword">p</span>"#,
+            r#"<span class="punctuation">&gt;</span> goodbye</code></pre>"#,
+        )
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "lang-ini")]
+mod common;
+
+use cmark_syntax::languages::Ini;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn ini_highlight() {
+    let source = concat!(
+        "; settings\n",
+        "[server]\n",
+        "host name = example.com\n",
+        "url: http://example.com:80\n",
+        "color = #fff ; [x]\n",
+        "# done\n",
+        "empty =\n",
+    );
+    assert_eq!(
+        kinds::<Ini>(source),
+        [
+            (Kind::Comment, "; settings"),
+            (Kind::StrongIdentifier, "[server]"),
+            (Kind::Identifier, "host"),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "example.com"),
+            (Kind::Identifier, "url"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "http"),
+            (Kind::String, ":"),
+            (Kind::String, "//example.com"),
+            (Kind::String, ":"),
+            (Kind::String, "80"),
+            (Kind::Identifier, "color"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "#fff"),
+            (Kind::Comment, "; [x]"),
+            (Kind::Comment, "# done"),
+            (Kind::Identifier, "empty"),
+            (Kind::Punctuation, "="),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Ini>(source),
+        concat!(
+            "<span class=\"comment\">; settings</span>\n",
+            "<span class=\"strong-identifier\">[server]</span>\n",
+            "<span class=\"identifier\">host</span> <span class=\"identifier\">name</span> <span class=\"punctuation\">=</span> <span class=\"string\">example.com</span>\n",
+            "<span class=\"identifier\">url</span><span class=\"punctuation\">:</span> <span class=\"string\">http://example.com:80</span>\n",
+            "<span class=\"identifier\">color</span> <span class=\"punctuation\">=</span> <span class=\"string\">#fff</span> <span class=\"comment\">; [x]</span>\n",
+            "<span class=\"comment\"># done</span>\n",
+            "<span class=\"identifier\">empty</span> <span class=\"punctuation\">=</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("properties"), Some(Language::Ini));
+}
+
+// Comments after a value need whitespace before them, as values may hold `;` and `#`
+#[test]
+fn inline_comment() {
+    let source = "key = a;b # c: d\nurl = x#y ; z\n";
+    assert_eq!(
+        kinds::<Ini>(source),
+        [
+            (Kind::Identifier, "key"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "a;b"),
+            (Kind::Comment, "# c: d"),
+            (Kind::Identifier, "url"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "x#y"),
+            (Kind::Comment, "; z"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Ini>("a = b ; c"),
+        concat!(
+            "<span class=\"identifier\">a</span> <span class=\"punctuation\">=</span> ",
+            "<span class=\"string\">b</span> <span class=\"comment\">; c</span>",
+        )
+    );
+}
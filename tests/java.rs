@@ -0,0 +1,61 @@
+#![cfg(feature = "lang-java")]
+mod common;
+
+use cmark_syntax::languages::Java;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn java_highlight() {
+    let source = concat!(
+        "public class Main {\n",
+        "    @Override\n",
+        "    List<String> names(long n) { return List.of(\"a\", 10L); } // done\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Java>(source),
+        [
+            (Kind::Keyword, "public"),
+            (Kind::Keyword, "class"),
+            (Kind::SpecialIdentifier, "Main"),
+            (Kind::Punctuation, "{"),
+            (Kind::Glyph, "@Override"),
+            (Kind::StrongIdentifier, "List"),
+            (Kind::Operator, "<"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Operator, ">"),
+            (Kind::Function, "names"),
+            (Kind::Punctuation, "("),
+            (Kind::Type, "long"),
+            (Kind::Identifier, "n"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "return"),
+            (Kind::StrongIdentifier, "List"),
+            (Kind::Punctuation, "."),
+            (Kind::Function, "of"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "\"a\""),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "10L"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::Comment, "// done"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Java>(source),
+        concat!(
+            "<span class=\"keyword\">public</span> <span class=\"keyword\">class</span> <span class=\"special-identifier\">Main</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"glyph\">@Override</span>\n",
+            "    <span class=\"strong-identifier\">List</span><span class=\"operator\">&lt;</span><span class=\"strong-identifier\">String</span><span class=\"operator\">&gt;</span> <span class=\"function\">names</span><span class=\"punctuation\">(</span><span class=\"type\">long</span> <span class=\"identifier\">n</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">return</span> <span class=\"strong-identifier\">List</span><span class=\"punctuation\">.</span><span class=\"function\">of</span><span class=\"punctuation\">(</span><span class=\"string\">&quot;a&quot;</span><span class=\"punctuation\">,</span> <span class=\"number\">10L</span><span class=\"punctuation\">);</span> <span class=\"punctuation\">}</span> <span class=\"comment\">// done</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    let text_block = "\"\"\"\n    Hello, \"world\"!\n    \"\"\"";
+    assert_eq!(kinds::<Java>(text_block), [(Kind::String, text_block)]);
+    assert_eq!(Language::from_name("java"), Some(Language::Java));
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "lang-json")]
+mod common;
+
+use cmark_syntax::languages::Json;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn json_highlight() {
+    let source = "{\"name\" : \"cmark\", \"tags\": [1, -2.5e3, true, null]}";
+    assert_eq!(
+        kinds::<Json>(source),
+        [
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "\"name\""),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "\"cmark\""),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "\"tags\""),
+            (Kind::Punctuation, ":"),
+            (Kind::Punctuation, "["),
+            (Kind::Number, "1"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "-2.5e3"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "true"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "null"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Json>(source),
+        "<span class=\"punctuation\">{</span><span class=\"identifier\">&quot;name&quot;</span> <span class=\"punctuation\">:</span> <span class=\"string\">&quot;cmark&quot;</span><span class=\"punctuation\">,</span> <span class=\"identifier\">&quot;tags&quot;</span><span class=\"punctuation\">:</span> <span class=\"punctuation\">[</span><span class=\"number\">1</span><span class=\"punctuation\">,</span> <span class=\"number\">-2.5e3</span><span class=\"punctuation\">,</span> <span class=\"literal\">true</span><span class=\"punctuation\">,</span> <span class=\"literal\">null</span><span class=\"punctuation\">]}</span>"
+    );
+    assert_eq!(kinds::<Json>("\"a\\\"b\""), [(Kind::String, "\"a\\\"b\"")]);
+    assert_eq!(Language::from_name("json"), Some(Language::Json));
+}
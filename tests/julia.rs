@@ -0,0 +1,53 @@
+#![cfg(feature = "lang-julia")]
+mod common;
+
+use cmark_syntax::languages::Julia;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn julia_highlight() {
+    let source = concat!(
+        "#= outer #= inner =# outer =#\n",
+        "struct Point x::Float64 end # a point\n",
+        "function greet(name)\n",
+        "    @time println(\"Hi $(uppercase(name)), $name\")\n",
+        "end\n",
+    );
+    assert_eq!(
+        kinds::<Julia>(source),
+        [
+            (Kind::Comment, "#= outer #= inner =# outer =#"),
+            (Kind::Keyword, "struct"),
+            (Kind::SpecialIdentifier, "Point"),
+            (Kind::Identifier, "x"),
+            (Kind::Operator, "::"),
+            (Kind::StrongIdentifier, "Float64"),
+            (Kind::Keyword, "end"),
+            (Kind::Comment, "# a point"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Glyph, "@time"),
+            (Kind::Function, "println"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "\"Hi $(uppercase(name)), $name\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "end"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Julia>(source),
+        concat!(
+            "<span class=\"comment\">#= outer #= inner =# outer =#</span>\n",
+            "<span class=\"keyword\">struct</span> <span class=\"special-identifier\">Point</span> <span class=\"identifier\">x</span><span class=\"operator\">::</span><span class=\"strong-identifier\">Float64</span> <span class=\"keyword\">end</span> <span class=\"comment\"># a point</span>\n",
+            "<span class=\"keyword\">function</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">)</span>\n",
+            "    <span class=\"glyph\">@time</span> <span class=\"function\">println</span><span class=\"punctuation\">(</span><span class=\"string\">&quot;Hi $(uppercase(name)), $name&quot;</span><span class=\"punctuation\">)</span>\n",
+            "<span class=\"keyword\">end</span>\n",
+        )
+    );
+    assert_eq!(kinds::<Julia>("1_000im"), [(Kind::Number, "1_000im")]);
+    assert_eq!(Language::from_name("jl"), Some(Language::Julia));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "lang-kotlin")]
+mod common;
+
+use cmark_syntax::languages::Kotlin;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn kotlin_highlight() {
+    let source = concat!(
+        "@JvmStatic /* a /* nested */ comment */\n",
+        "fun greet(name: String): String {\n",
+        "    val size = name.length // count\n",
+        "    return \"Hi $name, ${size * 2}!\"\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Kotlin>(source),
+        [
+            (Kind::Glyph, "@JvmStatic"),
+            (Kind::Comment, "/* a /* nested */ comment */"),
+            (Kind::Keyword, "fun"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "val"),
+            (Kind::Identifier, "size"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "length"),
+            (Kind::Comment, "// count"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "\"Hi $name, ${size * 2}!\""),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Kotlin>(source),
+        concat!(
+            "<span class=\"glyph\">@JvmStatic</span> <span class=\"comment\">/* a /* nested */ comment */</span>\n",
+            "<span class=\"keyword\">fun</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">:</span> <span class=\"strong-identifier\">String</span><span class=\"punctuation\">):</span> <span class=\"strong-identifier\">String</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">val</span> <span class=\"identifier\">size</span> <span class=\"operator\">=</span> <span class=\"identifier\">name</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">length</span> <span class=\"comment\">// count</span>\n",
+            "    <span class=\"keyword\">return</span> <span class=\"string\">&quot;Hi $name, ${size * 2}!&quot;</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    let raw = "\"\"\"\n    C:\\path \"quoted\" $x\n\"\"\"";
+    assert_eq!(kinds::<Kotlin>(raw), [(Kind::String, raw)]);
+    assert_eq!(Language::from_name("kt"), Some(Language::Kotlin));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "lang-lua")]
+mod common;
+
+use cmark_syntax::languages::Lua;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn lua_highlight() {
+    let source = concat!(
+        "--[[ a block\ncomment ]] -- and a line\n",
+        "local function greet(name)\n",
+        "  return [==[Hi ]] ]==] .. name:upper()\n",
+        "end\n",
+        "--[==[ ]] ]==]\n",
+    );
+    assert_eq!(
+        kinds::<Lua>(source),
+        [
+            (Kind::Comment, "--[[ a block\ncomment ]]"),
+            (Kind::Comment, "-- and a line"),
+            (Kind::Keyword, "local"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "[==[Hi ]] ]==]"),
+            (Kind::Operator, ".."),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::Function, "upper"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "end"),
+            (Kind::Comment, "--[==[ ]] ]==]"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Lua>(source),
+        concat!(
+            "<span class=\"comment\">--[[ a block\n",
+            "comment ]]</span> <span class=\"comment\">-- and a line</span>\n",
+            "<span class=\"keyword\">local</span> <span class=\"keyword\">function</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">)</span>\n",
+            "  <span class=\"keyword\">return</span> <span class=\"string\">[==[Hi ]] ]==]</span> <span class=\"operator\">..</span> <span class=\"identifier\">name</span><span class=\"punctuation\">:</span><span class=\"function\">upper</span><span class=\"punctuation\">()</span>\n",
+            "<span class=\"keyword\">end</span>\n",
+            "<span class=\"comment\">--[==[ ]] ]==]</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Lua>("--[ not long"),
+        [(Kind::Comment, "--[ not long")]
+    );
+    assert_eq!(Language::from_name("lua"), Some(Language::Lua));
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "lang-makefile")]
+mod common;
+
+use cmark_syntax::languages::Makefile;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn makefile_highlight() {
+    let source = concat!(
+        "# settings\n",
+        "CC ?= cc\n",
+        "OBJ := $(patsubst %.c,%.o,$(SRC))\n",
+        "ifdef DEBUG\n",
+        "endif\n",
+        "app: $(OBJ) main.o\n",
+        "\t$(CC) -o $@ $^ # link\n",
+        "\techo a: b\n",
+    );
+    assert_eq!(
+        kinds::<Makefile>(source),
+        [
+            (Kind::Comment, "# settings"),
+            (Kind::SpecialIdentifier, "CC"),
+            (Kind::Operator, "?="),
+            (Kind::SpecialIdentifier, "OBJ"),
+            (Kind::Operator, ":="),
+            (Kind::Identifier, "$(patsubst %.c,%.o,$(SRC))"),
+            (Kind::Keyword, "ifdef"),
+            (Kind::Keyword, "endif"),
+            (Kind::Function, "app"),
+            (Kind::Punctuation, ":"),
+            (Kind::Identifier, "$(OBJ)"),
+            (Kind::Identifier, "$(CC)"),
+            (Kind::Identifier, "$@"),
+            (Kind::Identifier, "$^"),
+            (Kind::Comment, "# link"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Makefile>(source),
+        concat!(
+            "<span class=\"comment\"># settings</span>\n",
+            "<span class=\"special-identifier\">CC</span> <span class=\"operator\">?=</span> cc\n",
+            "<span class=\"special-identifier\">OBJ</span> <span class=\"operator\">:=</span> <span class=\"identifier\">$(patsubst %.c,%.o,$(SRC))</span>\n",
+            "<span class=\"keyword\">ifdef</span> DEBUG\n",
+            "<span class=\"keyword\">endif</span>\n",
+            "<span class=\"function\">app</span><span class=\"punctuation\">:</span> <span class=\"identifier\">$(OBJ)</span> main.o\n",
+            "\t<span class=\"identifier\">$(CC)</span> -o <span class=\"identifier\">$@</span> <span class=\"identifier\">$^</span> <span class=\"comment\"># link</span>\n",
+            "\techo a: b\n",
+        )
+    );
+    assert_eq!(Language::from_name("mk"), Some(Language::Makefile));
+}
@@ -1,7 +1,8 @@
+#![cfg(feature = "std")]
 #[cfg(feature = "latex2mathml")]
-use cmark_syntax::SyntaxPreprocessor;
+use cmark_syntax::{SyntaxConfig, SyntaxPreprocessor};
 #[cfg(feature = "latex2mathml")]
-use pulldown_cmark::{html, Parser, Options};
+use pulldown_cmark::{html, Options, Parser};
 
 // Test highlighting rust code
 #[cfg(feature = "latex2mathml")]
@@ -16,3 +17,59 @@ fn math_highlight() {
     // let _ = std::fs::write("math.html", &rendered);
     assert_eq!(rendered, include_str!("math.html"));
 }
+
+#[cfg(feature = "latex2mathml")]
+#[test]
+fn math_error() {
+    let parser = Parser::new_ext("broken $\\left($ math", Options::ENABLE_MATH);
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    assert_eq!(
+        rendered,
+        concat!(
+            "<p>broken <span class=\"math-error\">The token &quot;Right&quot; is expected, ",
+            "but the token &quot;EOF&quot; is found.&quot;</span> math</p>\n"
+        )
+    );
+}
+
+#[cfg(feature = "latex2mathml")]
+#[test]
+fn math_languages() {
+    let config = SyntaxConfig::new().with_block_math_language("latex");
+    let parser = Parser::new("```latex\nx^2\n```\n\n```mathblock\nx^2\n```\n");
+    let processed = SyntaxPreprocessor::with_config(parser, config);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    let block = "<math xmlns=\"http://www.w3.org/1998/Math/MathML\" display=\"block\">";
+    assert_eq!(rendered.matches(block).count(), 2);
+}
+
+#[cfg(feature = "latex2mathml")]
+#[test]
+fn math_toggle() {
+    let render = |config: SyntaxConfig| {
+        let parser = Parser::new_ext("$x$\n\n```math\nx < y\n```\n", Options::ENABLE_MATH);
+        let mut rendered = String::new();
+        html::push_html(
+            &mut rendered,
+            SyntaxPreprocessor::with_config(parser, config),
+        );
+        rendered
+    };
+
+    let enabled = render(SyntaxConfig::new());
+    assert_eq!(enabled.matches("<math ").count(), 2);
+
+    let disabled = render(SyntaxConfig::new().with_math(false));
+    assert_eq!(
+        disabled,
+        concat!(
+            "<p><span class=\"math math-inline\">x</span></p>\n",
+            "<pre><code class=\"language-math\">x &lt; y</code></pre>"
+        )
+    );
+}
@@ -0,0 +1,77 @@
+#![cfg(all(feature = "mdbook", feature = "lang-rust", feature = "lang-sh"))]
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const PREPROCESSOR: &str = env!("CARGO_BIN_EXE_mdbook-cmark-syntax");
+
+#[test]
+fn supports() {
+    let supports = |renderer| {
+        Command::new(PREPROCESSOR)
+            .args(["supports", renderer])
+            .status()
+            .unwrap()
+            .success()
+    };
+    assert!(supports("html"));
+    assert!(!supports("markdown"));
+}
+
+#[test]
+fn book() {
+    let chapter = |content: &str, sub_items: Value| {
+        json!({"Chapter": {
+            "name": "Chapter",
+            "content": content,
+            "number": [1],
+            "sub_items": sub_items,
+            "path": "chapter.md",
+            "source_path": "chapter.md",
+            "parent_names": [],
+        }})
+    };
+    let context = json!({
+        "root": "/book",
+        "config": {"book": {"src": "src"}},
+        "renderer": "html",
+        "mdbook_version": "0.4.40",
+    });
+    let nested = chapter("- item\n\n  ```sh\n  ls\n\n  ls\n  ```\n", json!([]));
+    let book = json!({
+        "sections": [
+            chapter("# Title\n\n```rust\n# fn main() {\nlet x = 1;\n# }\n```\nText\n", json!([nested])),
+            "Separator",
+        ],
+        "__non_exhaustive": null,
+    });
+
+    let mut child = Command::new(PREPROCESSOR)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let input = serde_json::to_vec(&json!([context, book])).unwrap();
+    child.stdin.take().unwrap().write_all(&input).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let book: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let chapter = &book["sections"][0]["Chapter"];
+    assert_eq!(
+        chapter["content"],
+        concat!(
+            "# Title\n\n<pre><code class=\"language-rust\"><span class=\"keyword\">let</span> ",
+            "<span class=\"identifier\">x</span> <span class=\"operator\">=</span> ",
+            "<span class=\"number\">1</span>;</code></pre>\nText\n"
+        )
+    );
+    assert_eq!(
+        chapter["sub_items"][0]["Chapter"]["content"],
+        concat!(
+            "- item\n\n  <pre><code class=\"language-sh\"><span class=\"strong-identifier\">ls",
+            "</span>&#10;&#10;<span class=\"strong-identifier\">ls</span></code></pre>\n"
+        )
+    );
+    assert_eq!(book["sections"][1], "Separator");
+}
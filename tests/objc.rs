@@ -0,0 +1,85 @@
+#![cfg(feature = "lang-objc")]
+mod common;
+
+use cmark_syntax::languages::ObjectiveC;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn objc_highlight() {
+    let source = concat!(
+        "#import <Foundation/Foundation.h>\n",
+        "@interface Greeter : NSObject\n",
+        "@property (nonatomic) int count;\n",
+        "- (void)greet:(NSString *)name; // say hi\n",
+        "@end\n",
+        "[greeter greet:@\"World\" times:2];\n",
+        "[list release];\n",
+    );
+    assert_eq!(
+        kinds::<ObjectiveC>(source),
+        [
+            (Kind::Keyword, "#import"),
+            (Kind::Operator, "<"),
+            (Kind::StrongIdentifier, "Foundation"),
+            (Kind::Operator, "/"),
+            (Kind::StrongIdentifier, "Foundation"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "h"),
+            (Kind::Operator, ">"),
+            (Kind::Keyword, "@interface"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "NSObject"),
+            (Kind::Keyword, "@property"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "nonatomic"),
+            (Kind::Punctuation, ")"),
+            (Kind::Type, "int"),
+            (Kind::Identifier, "count"),
+            (Kind::Punctuation, ";"),
+            (Kind::Operator, "-"),
+            (Kind::Punctuation, "("),
+            (Kind::Type, "void"),
+            (Kind::Punctuation, ")"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, ":"),
+            (Kind::Punctuation, "("),
+            (Kind::StrongIdentifier, "NSString"),
+            (Kind::Operator, "*"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "// say hi"),
+            (Kind::Keyword, "@end"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "greeter"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "@\"World\""),
+            (Kind::Function, "times"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "2"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "list"),
+            (Kind::Function, "release"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, ";"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<ObjectiveC>(source),
+        concat!(
+            "<span class=\"keyword\">#import</span> <span class=\"operator\">&lt;</span><span class=\"strong-identifier\">Foundation</span><span class=\"operator\">/</span><span class=\"strong-identifier\">Foundation</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">h</span><span class=\"operator\">&gt;</span>\n",
+            "<span class=\"keyword\">@interface</span> <span class=\"special-identifier\">Greeter</span> <span class=\"punctuation\">:</span> <span class=\"strong-identifier\">NSObject</span>\n",
+            "<span class=\"keyword\">@property</span> <span class=\"punctuation\">(</span><span class=\"keyword\">nonatomic</span><span class=\"punctuation\">)</span> <span class=\"type\">int</span> <span class=\"identifier\">count</span><span class=\"punctuation\">;</span>\n",
+            "<span class=\"operator\">-</span> <span class=\"punctuation\">(</span><span class=\"type\">void</span><span class=\"punctuation\">)</span><span class=\"function\">greet</span><span class=\"punctuation\">:(</span><span class=\"strong-identifier\">NSString</span> <span class=\"operator\">*</span><span class=\"punctuation\">)</span><span class=\"identifier\">name</span><span class=\"punctuation\">;</span> <span class=\"comment\">// say hi</span>\n",
+            "<span class=\"keyword\">@end</span>\n",
+            "<span class=\"punctuation\">[</span><span class=\"identifier\">greeter</span> <span class=\"function\">greet</span><span class=\"punctuation\">:</span><span class=\"string\">@&quot;World&quot;</span> <span class=\"function\">times</span><span class=\"punctuation\">:</span><span class=\"number\">2</span><span class=\"punctuation\">];</span>\n",
+            "<span class=\"punctuation\">[</span><span class=\"identifier\">list</span> <span class=\"function\">release</span><span class=\"punctuation\">];</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("m"), Some(Language::ObjectiveC));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "lang-ocaml")]
+mod common;
+
+use cmark_syntax::languages::Ocaml;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn ocaml_highlight() {
+    let source = concat!(
+        "(* outer (* inner *) outer *)\n",
+        "type 'a tree = Leaf | Node of 'a tree * 'a\n",
+        "let rec size ~acc = function\n",
+        "  | Leaf -> acc\n",
+        "  | Node (l, _) -> match l with _ -> 'x'\n",
+    );
+    assert_eq!(
+        kinds::<Ocaml>(source),
+        [
+            (Kind::Comment, "(* outer (* inner *) outer *)"),
+            (Kind::Keyword, "type"),
+            (Kind::Type, "'a"),
+            (Kind::Identifier, "tree"),
+            (Kind::Operator, "="),
+            (Kind::StrongIdentifier, "Leaf"),
+            (Kind::Operator, "|"),
+            (Kind::StrongIdentifier, "Node"),
+            (Kind::Keyword, "of"),
+            (Kind::Type, "'a"),
+            (Kind::Identifier, "tree"),
+            (Kind::Operator, "*"),
+            (Kind::Type, "'a"),
+            (Kind::Keyword, "let"),
+            (Kind::Keyword, "rec"),
+            (Kind::Identifier, "size"),
+            (Kind::SpecialIdentifier, "~acc"),
+            (Kind::Operator, "="),
+            (Kind::Keyword, "function"),
+            (Kind::Operator, "|"),
+            (Kind::StrongIdentifier, "Leaf"),
+            (Kind::Operator, "->"),
+            (Kind::Identifier, "acc"),
+            (Kind::Operator, "|"),
+            (Kind::StrongIdentifier, "Node"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "l"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "_"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "->"),
+            (Kind::Keyword, "match"),
+            (Kind::Identifier, "l"),
+            (Kind::Keyword, "with"),
+            (Kind::Identifier, "_"),
+            (Kind::Operator, "->"),
+            (Kind::String, "'x'"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Ocaml>(source),
+        concat!(
+            "<span class=\"comment\">(* outer (* inner *) outer *)</span>\n",
+            "<span class=\"keyword\">type</span> <span class=\"type\">&#39;a</span> <span class=\"identifier\">tree</span> <span class=\"operator\">=</span> <span class=\"strong-identifier\">Leaf</span> <span class=\"operator\">|</span> <span class=\"strong-identifier\">Node</span> <span class=\"keyword\">of</span> <span class=\"type\">&#39;a</span> <span class=\"identifier\">tree</span> <span class=\"operator\">*</span> <span class=\"type\">&#39;a</span>\n",
+            "<span class=\"keyword\">let</span> <span class=\"keyword\">rec</span> <span class=\"identifier\">size</span> <span class=\"special-identifier\">~acc</span> <span class=\"operator\">=</span> <span class=\"keyword\">function</span>\n",
+            "  <span class=\"operator\">|</span> <span class=\"strong-identifier\">Leaf</span> <span class=\"operator\">-&gt;</span> <span class=\"identifier\">acc</span>\n",
+            "  <span class=\"operator\">|</span> <span class=\"strong-identifier\">Node</span> <span class=\"punctuation\">(</span><span class=\"identifier\">l</span><span class=\"punctuation\">,</span> <span class=\"identifier\">_</span><span class=\"punctuation\">)</span> <span class=\"operator\">-&gt;</span> <span class=\"keyword\">match</span> <span class=\"identifier\">l</span> <span class=\"keyword\">with</span> <span class=\"identifier\">_</span> <span class=\"operator\">-&gt;</span> <span class=\"string\">&#39;x&#39;</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Ocaml>("{id|a \"|b|}|id}"),
+        [(Kind::String, "{id|a \"|b|}|id}")]
+    );
+    assert_eq!(Language::from_name("ml"), Some(Language::Ocaml));
+}
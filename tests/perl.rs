@@ -0,0 +1,75 @@
+#![cfg(feature = "lang-perl")]
+mod common;
+
+use cmark_syntax::languages::Perl;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn perl_highlight() {
+    let source = concat!(
+        "=pod\n\nmy $hidden;\n\n=cut\n",
+        "my @names = qw(a (b) c); # names\n",
+        "sub greet { my ($name) = @_; $name =~ s{a}{b}g; print <<\"EOT\"; }\n",
+        "Hi $name\n",
+        "EOT\n",
+        "my %ages = (x => 1);\n",
+    );
+    assert_eq!(
+        kinds::<Perl>(source),
+        [
+            (Kind::Comment, "=pod\n\nmy $hidden;\n\n=cut"),
+            (Kind::Keyword, "my"),
+            (Kind::SpecialIdentifier, "@names"),
+            (Kind::Operator, "="),
+            (Kind::String, "qw(a (b) c)"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "# names"),
+            (Kind::Keyword, "sub"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "my"),
+            (Kind::Punctuation, "("),
+            (Kind::SpecialIdentifier, "$name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "="),
+            (Kind::SpecialIdentifier, "@_"),
+            (Kind::Punctuation, ";"),
+            (Kind::SpecialIdentifier, "$name"),
+            (Kind::Operator, "=~"),
+            (Kind::String, "s{a}{b}g"),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "print"),
+            (Kind::String, "<<\"EOT\""),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::String, "\nHi $name\nEOT"),
+            (Kind::Keyword, "my"),
+            (Kind::SpecialIdentifier, "%ages"),
+            (Kind::Operator, "="),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "x"),
+            (Kind::Operator, "=>"),
+            (Kind::Number, "1"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Perl>(source),
+        concat!(
+            "<span class=\"comment\">=pod\n",
+            "\n",
+            "my $hidden;\n",
+            "\n",
+            "=cut</span>\n",
+            "<span class=\"keyword\">my</span> <span class=\"special-identifier\">@names</span> <span class=\"operator\">=</span> <span class=\"string\">qw(a (b) c)</span><span class=\"punctuation\">;</span> <span class=\"comment\"># names</span>\n",
+            "<span class=\"keyword\">sub</span> <span class=\"function\">greet</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">my</span> <span class=\"punctuation\">(</span><span class=\"special-identifier\">$name</span><span class=\"punctuation\">)</span> <span class=\"operator\">=</span> <span class=\"special-identifier\">@_</span><span class=\"punctuation\">;</span> <span class=\"special-identifier\">$name</span> <span class=\"operator\">=~</span> <span class=\"string\">s{a}{b}g</span><span class=\"punctuation\">;</span> <span class=\"keyword\">print</span> <span class=\"string\">&lt;&lt;&quot;EOT&quot;</span><span class=\"punctuation\">;</span> <span class=\"punctuation\">}</span><span class=\"string\">\n",
+            "Hi $name\n",
+            "EOT</span>\n",
+            "<span class=\"keyword\">my</span> <span class=\"special-identifier\">%ages</span> <span class=\"operator\">=</span> <span class=\"punctuation\">(</span><span class=\"identifier\">x</span> <span class=\"operator\">=&gt;</span> <span class=\"number\">1</span><span class=\"punctuation\">);</span>\n",
+        )
+    );
+    assert_eq!(kinds::<Perl>("s/a/b/"), [(Kind::String, "s/a/b/")]);
+    assert_eq!(Language::from_name("pl"), Some(Language::Perl));
+}
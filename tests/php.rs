@@ -0,0 +1,79 @@
+#![cfg(feature = "lang-php")]
+mod common;
+
+use cmark_syntax::languages::Php;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn php_highlight() {
+    let source = concat!(
+        "<h1>if (\"title\") $x</h1>\n",
+        "<?php\n",
+        "function greet($name) { // hi ?>",
+        "<p>echo</p><?php\n",
+        "    return \"Hi {$name}!\" . <<<EOT\n",
+        "    $name EOT\n",
+        "    EOT;\n",
+        "}\n",
+        "?>\n",
+        "<p>done</p>\n",
+    );
+    assert_eq!(
+        kinds::<Php>(source),
+        [
+            (Kind::Glyph, "<?php"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "$name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Comment, "// hi "),
+            (Kind::Glyph, "?>"),
+            (Kind::Glyph, "<?php"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "\"Hi {$name}!\""),
+            (Kind::Operator, "."),
+            (Kind::String, "<<<EOT\n    $name EOT\n    EOT"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::Glyph, "?>"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Php>(source),
+        concat!(
+            "&lt;h1&gt;if (&quot;title&quot;) $x&lt;/h1&gt;\n",
+            "<span class=\"glyph\">&lt;?php</span>\n",
+            "<span class=\"keyword\">function</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">$name</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"comment\">// hi </span><span class=\"glyph\">?&gt;</span>&lt;p&gt;echo&lt;/p&gt;<span class=\"glyph\">&lt;?php</span>\n",
+            "    <span class=\"keyword\">return</span> <span class=\"string\">&quot;Hi {$name}!&quot;</span> <span class=\"operator\">.</span> <span class=\"string\">&lt;&lt;&lt;EOT\n",
+            "    $name EOT\n",
+            "    EOT</span><span class=\"punctuation\">;</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+            "<span class=\"glyph\">?&gt;</span>\n",
+            "&lt;p&gt;done&lt;/p&gt;\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Php>("$x = NULL;"),
+        [
+            (Kind::Identifier, "$x"),
+            (Kind::Operator, "="),
+            (Kind::Literal, "NULL"),
+            (Kind::Punctuation, ";"),
+        ]
+    );
+    assert_eq!(Language::from_name("php"), Some(Language::Php));
+}
+
+#[test]
+fn php_text() {
+    assert_eq!(
+        highlight_to_string::<Php>("a <?php $x ?> b"),
+        concat!(
+            r#"a <span class="glyph">&lt;?php</span> <span class="identifier">$x</span> "#,
+            r#"<span class="glyph">?&gt;</span> b"#,
+        )
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "lang-powershell")]
+mod common;
+
+use cmark_syntax::languages::PowerShell;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn powershell_highlight() {
+    let source = concat!(
+        "<# Greet #>\n",
+        "function Say-Hello([string]$name) {\n",
+        "    if ($name -eq $null) { return }\n",
+        "    Write-Host -Object \"Hi $($name.Trim(\"x\")) from $env:USER\" # done\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<PowerShell>(source),
+        [
+            (Kind::Comment, "<# Greet #>"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "Say-Hello"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, "["),
+            (Kind::Type, "string"),
+            (Kind::Punctuation, "]"),
+            (Kind::SpecialIdentifier, "$name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "if"),
+            (Kind::Punctuation, "("),
+            (Kind::SpecialIdentifier, "$name"),
+            (Kind::Operator, "-eq"),
+            (Kind::Literal, "$null"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "return"),
+            (Kind::Punctuation, "}"),
+            (Kind::Function, "Write-Host"),
+            (Kind::Glyph, "-Object"),
+            (Kind::String, "\"Hi $($name.Trim(\"x\")) from $env:USER\""),
+            (Kind::Comment, "# done"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<PowerShell>(source),
+        concat!(
+            "<span class=\"comment\">&lt;# Greet #&gt;</span>\n",
+            "<span class=\"keyword\">function</span> <span class=\"function\">Say-Hello</span><span class=\"punctuation\">([</span><span class=\"type\">string</span><span class=\"punctuation\">]</span><span class=\"special-identifier\">$name</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">if</span> <span class=\"punctuation\">(</span><span class=\"special-identifier\">$name</span> <span class=\"operator\">-eq</span> <span class=\"literal\">$null</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">return</span> <span class=\"punctuation\">}</span>\n",
+            "    <span class=\"function\">Write-Host</span> <span class=\"glyph\">-Object</span> <span class=\"string\">&quot;Hi $($name.Trim(&quot;x&quot;)) from $env:USER&quot;</span> <span class=\"comment\"># done</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("ps1"), Some(Language::PowerShell));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "lang-protobuf")]
+mod common;
+
+use cmark_syntax::languages::Protobuf;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn protobuf_highlight() {
+    let source = concat!(
+        "syntax = \"proto3\";\n",
+        "option java_package = \"x\";\n",
+        "message User {\n",
+        "  repeated string tags = 2 [deprecated = true]; // old\n",
+        "}\n",
+        "service Users { rpc Get(User) returns (User); }\n",
+    );
+    assert_eq!(
+        kinds::<Protobuf>(source),
+        [
+            (Kind::Keyword, "syntax"),
+            (Kind::Operator, "="),
+            (Kind::String, "\"proto3\""),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "option"),
+            (Kind::SpecialIdentifier, "java_package"),
+            (Kind::Operator, "="),
+            (Kind::String, "\"x\""),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "message"),
+            (Kind::SpecialIdentifier, "User"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "repeated"),
+            (Kind::Type, "string"),
+            (Kind::Identifier, "tags"),
+            (Kind::Operator, "="),
+            (Kind::Number, "2"),
+            (Kind::Punctuation, "["),
+            (Kind::Identifier, "deprecated"),
+            (Kind::Operator, "="),
+            (Kind::Literal, "true"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "// old"),
+            (Kind::Punctuation, "}"),
+            (Kind::Keyword, "service"),
+            (Kind::SpecialIdentifier, "Users"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "rpc"),
+            (Kind::Function, "Get"),
+            (Kind::Punctuation, "("),
+            (Kind::StrongIdentifier, "User"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "returns"),
+            (Kind::Punctuation, "("),
+            (Kind::StrongIdentifier, "User"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Protobuf>(source),
+        concat!(
+            "<span class=\"keyword\">syntax</span> <span class=\"operator\">=</span> <span class=\"string\">&quot;proto3&quot;</span><span class=\"punctuation\">;</span>\n",
+            "<span class=\"keyword\">option</span> <span class=\"special-identifier\">java_package</span> <span class=\"operator\">=</span> <span class=\"string\">&quot;x&quot;</span><span class=\"punctuation\">;</span>\n",
+            "<span class=\"keyword\">message</span> <span class=\"special-identifier\">User</span> <span class=\"punctuation\">{</span>\n",
+            "  <span class=\"keyword\">repeated</span> <span class=\"type\">string</span> <span class=\"identifier\">tags</span> <span class=\"operator\">=</span> <span class=\"number\">2</span> <span class=\"punctuation\">[</span><span class=\"identifier\">deprecated</span> <span class=\"operator\">=</span> <span class=\"literal\">true</span><span class=\"punctuation\">];</span> <span class=\"comment\">// old</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+            "<span class=\"keyword\">service</span> <span class=\"special-identifier\">Users</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">rpc</span> <span class=\"function\">Get</span><span class=\"punctuation\">(</span><span class=\"strong-identifier\">User</span><span class=\"punctuation\">)</span> <span class=\"keyword\">returns</span> <span class=\"punctuation\">(</span><span class=\"strong-identifier\">User</span><span class=\"punctuation\">);</span> <span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("proto"), Some(Language::Protobuf));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "lang-python")]
+mod common;
+
+use cmark_syntax::languages::Python;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn python_highlight() {
+    let source = concat!(
+        "@cache\n",
+        "async def fetch(self, url: str) -> bytes:\n",
+        "    \"\"\"Fetch \"the\" url.\"\"\"\n",
+        "    return await get(f'{url}?q=1', 0x1F, 1.5e3)  # done\n",
+    );
+    assert_eq!(
+        kinds::<Python>(source),
+        [
+            (Kind::Glyph, "@cache"),
+            (Kind::Keyword, "async"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "fetch"),
+            (Kind::Punctuation, "("),
+            (Kind::SpecialIdentifier, "self"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "url"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "str"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "->"),
+            (Kind::Type, "bytes"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "\"\"\"Fetch \"the\" url.\"\"\""),
+            (Kind::Keyword, "return"),
+            (Kind::Keyword, "await"),
+            (Kind::Identifier, "get"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "f'{url}?q=1'"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "0x1F"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "1.5e3"),
+            (Kind::Punctuation, ")"),
+            (Kind::Comment, "# done"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Python>(source),
+        concat!(
+            "<span class=\"glyph\">@cache</span>\n",
+            "<span class=\"keyword\">async</span> <span class=\"keyword\">def</span> <span class=\"function\">fetch</span><span class=\"punctuation\">(</span><span class=\"special-identifier\">self</span><span class=\"punctuation\">,</span> <span class=\"identifier\">url</span><span class=\"punctuation\">:</span> <span class=\"type\">str</span><span class=\"punctuation\">)</span> <span class=\"operator\">-&gt;</span> <span class=\"type\">bytes</span><span class=\"punctuation\">:</span>\n",
+            "    <span class=\"string\">&quot;&quot;&quot;Fetch &quot;the&quot; url.&quot;&quot;&quot;</span>\n",
+            "    <span class=\"keyword\">return</span> <span class=\"keyword\">await</span> <span class=\"identifier\">get</span><span class=\"punctuation\">(</span><span class=\"string\">f&#39;{url}?q=1&#39;</span><span class=\"punctuation\">,</span> <span class=\"number\">0x1F</span><span class=\"punctuation\">,</span> <span class=\"number\">1.5e3</span><span class=\"punctuation\">)</span>  <span class=\"comment\"># done</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Python>("class Point: pass")[1],
+        (Kind::SpecialIdentifier, "Point")
+    );
+    assert_eq!(Language::from_name("py"), Some(Language::Python));
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "lang-c", feature = "lang-r"))]
+mod common;
+
+use cmark_syntax::languages::R;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn r_highlight() {
+    let source = concat!(
+        "square <- function(x) x^2 # squares\n",
+        "10L -> n; y = c(1.5, 2i, NA)\n",
+        "df %>% filter(n > 1) |> head()\n",
+    );
+    assert_eq!(
+        kinds::<R>(source),
+        [
+            (Kind::Identifier, "square"),
+            (Kind::Operator, "<-"),
+            (Kind::Keyword, "function"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "x"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "x"),
+            (Kind::Operator, "^"),
+            (Kind::Number, "2"),
+            (Kind::Comment, "# squares"),
+            (Kind::Number, "10L"),
+            (Kind::Operator, "->"),
+            (Kind::Identifier, "n"),
+            (Kind::Punctuation, ";"),
+            (Kind::Identifier, "y"),
+            (Kind::Operator, "="),
+            (Kind::Function, "c"),
+            (Kind::Punctuation, "("),
+            (Kind::Number, "1.5"),
+            (Kind::Punctuation, ","),
+            (Kind::Number, "2i"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "NA"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "df"),
+            (Kind::Operator, "%>%"),
+            (Kind::Function, "filter"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "n"),
+            (Kind::Operator, ">"),
+            (Kind::Number, "1"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "|>"),
+            (Kind::Function, "head"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<R>(source),
+        concat!(
+            "<span class=\"identifier\">square</span> <span class=\"operator\">&lt;-</span> <span class=\"keyword\">function</span><span class=\"punctuation\">(</span><span class=\"identifier\">x</span><span class=\"punctuation\">)</span> <span class=\"identifier\">x</span><span class=\"operator\">^</span><span class=\"number\">2</span> <span class=\"comment\"># squares</span>\n",
+            "<span class=\"number\">10L</span> <span class=\"operator\">-&gt;</span> <span class=\"identifier\">n</span><span class=\"punctuation\">;</span> <span class=\"identifier\">y</span> <span class=\"operator\">=</span> <span class=\"function\">c</span><span class=\"punctuation\">(</span><span class=\"number\">1.5</span><span class=\"punctuation\">,</span> <span class=\"number\">2i</span><span class=\"punctuation\">,</span> <span class=\"literal\">NA</span><span class=\"punctuation\">)</span>\n",
+            "<span class=\"identifier\">df</span> <span class=\"operator\">%&gt;%</span> <span class=\"function\">filter</span><span class=\"punctuation\">(</span><span class=\"identifier\">n</span> <span class=\"operator\">&gt;</span> <span class=\"number\">1</span><span class=\"punctuation\">)</span> <span class=\"operator\">|&gt;</span> <span class=\"function\">head</span><span class=\"punctuation\">()</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("r"), Some(Language::R));
+    // Other single letter fences are left to their own languages
+    assert_eq!(Language::from_name("c"), Some(Language::C));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "lang-ruby")]
+mod common;
+
+use cmark_syntax::languages::Ruby;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn ruby_highlight() {
+    let source = concat!(
+        "=begin\nclass Hidden\n=end\n",
+        "class Greeter\n",
+        "  def greet(name) # say hi\n",
+        "    @count += 1\n",
+        "    log(:greet, level: @@level, words: %w[hi (there)])\n",
+        "    \"Hi #{name.upcase}!\"\n",
+        "  end\n",
+        "end\n",
+    );
+    assert_eq!(
+        kinds::<Ruby>(source),
+        [
+            (Kind::Comment, "=begin\nclass Hidden\n=end"),
+            (Kind::Keyword, "class"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Comment, "# say hi"),
+            (Kind::SpecialIdentifier, "@count"),
+            (Kind::Operator, "+"),
+            (Kind::Operator, "="),
+            (Kind::Number, "1"),
+            (Kind::Identifier, "log"),
+            (Kind::Punctuation, "("),
+            (Kind::Literal, ":greet"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "level"),
+            (Kind::Punctuation, ":"),
+            (Kind::SpecialIdentifier, "@@level"),
+            (Kind::Punctuation, ","),
+            (Kind::Literal, "words"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "%w[hi (there)]"),
+            (Kind::Punctuation, ")"),
+            (Kind::String, "\"Hi #{name.upcase}!\""),
+            (Kind::Keyword, "end"),
+            (Kind::Keyword, "end"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Ruby>(source),
+        concat!(
+            "<span class=\"comment\">=begin\n",
+            "class Hidden\n",
+            "=end</span>\n",
+            "<span class=\"keyword\">class</span> <span class=\"special-identifier\">Greeter</span>\n",
+            "  <span class=\"keyword\">def</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">)</span> <span class=\"comment\"># say hi</span>\n",
+            "    <span class=\"special-identifier\">@count</span> <span class=\"operator\">+=</span> <span class=\"number\">1</span>\n",
+            "    <span class=\"identifier\">log</span><span class=\"punctuation\">(</span><span class=\"literal\">:greet</span><span class=\"punctuation\">,</span> <span class=\"literal\">level</span><span class=\"punctuation\">:</span> <span class=\"special-identifier\">@@level</span><span class=\"punctuation\">,</span> <span class=\"literal\">words</span><span class=\"punctuation\">:</span> <span class=\"string\">%w[hi (there)]</span><span class=\"punctuation\">)</span>\n",
+            "    <span class=\"string\">&quot;Hi #{name.upcase}!&quot;</span>\n",
+            "  <span class=\"keyword\">end</span>\n",
+            "<span class=\"keyword\">end</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("rb"), Some(Language::Ruby));
+}
@@ -1,3 +1,4 @@
+#![cfg(all(feature = "lang-rust", feature = "std"))]
 use cmark_syntax::SyntaxPreprocessor;
 use pulldown_cmark::{html, Parser};
 
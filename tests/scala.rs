@@ -0,0 +1,62 @@
+#![cfg(feature = "lang-scala")]
+mod common;
+
+use cmark_syntax::languages::Scala;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn scala_highlight() {
+    let source = concat!(
+        "trait Greeter /* base */\n",
+        "@main object Main extends Greeter {\n",
+        "  def greet(name: String) = println(s\"Hi $name, ${name.length}\") // hi\n",
+        "  val tag = 'greeting\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Scala>(source),
+        [
+            (Kind::Keyword, "trait"),
+            (Kind::SpecialIdentifier, "Greeter"),
+            (Kind::Comment, "/* base */"),
+            (Kind::Glyph, "@main"),
+            (Kind::Keyword, "object"),
+            (Kind::SpecialIdentifier, "Main"),
+            (Kind::Keyword, "extends"),
+            (Kind::StrongIdentifier, "Greeter"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "def"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "="),
+            (Kind::Function, "println"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "s\"Hi $name, ${name.length}\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Comment, "// hi"),
+            (Kind::Keyword, "val"),
+            (Kind::Identifier, "tag"),
+            (Kind::Operator, "="),
+            (Kind::Literal, "'greeting"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Scala>(source),
+        concat!(
+            "<span class=\"keyword\">trait</span> <span class=\"special-identifier\">Greeter</span> <span class=\"comment\">/* base */</span>\n",
+            "<span class=\"glyph\">@main</span> <span class=\"keyword\">object</span> <span class=\"special-identifier\">Main</span> <span class=\"keyword\">extends</span> <span class=\"strong-identifier\">Greeter</span> <span class=\"punctuation\">{</span>\n",
+            "  <span class=\"keyword\">def</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">:</span> <span class=\"strong-identifier\">String</span><span class=\"punctuation\">)</span> <span class=\"operator\">=</span> <span class=\"function\">println</span><span class=\"punctuation\">(</span><span class=\"string\">s&quot;Hi $name, ${name.length}&quot;</span><span class=\"punctuation\">)</span> <span class=\"comment\">// hi</span>\n",
+            "  <span class=\"keyword\">val</span> <span class=\"identifier\">tag</span> <span class=\"operator\">=</span> <span class=\"literal\">&#39;greeting</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    let raw = "raw\"\"\"a \"b\" \\n\"\"\"";
+    assert_eq!(kinds::<Scala>(raw), [(Kind::String, raw)]);
+    assert_eq!(Language::from_name("scala"), Some(Language::Scala));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "lang-scheme")]
+mod common;
+
+use cmark_syntax::languages::Scheme;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn scheme_highlight() {
+    let source = concat!(
+        "#| outer #| inner |# still |#\n",
+        "(define (greet name) ; say hi\n",
+        "  (let ((s (string-append \"Hi \" name)))\n",
+        "    (if #t `(,s #\\! -1/2) '())))\n",
+    );
+    assert_eq!(
+        kinds::<Scheme>(source),
+        [
+            (Kind::Comment, "#| outer #| inner |# still |#"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "define"),
+            (Kind::Punctuation, "("),
+            (Kind::Function, "greet"),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Comment, "; say hi"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "let"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, "("),
+            (Kind::Function, "s"),
+            (Kind::Punctuation, "("),
+            (Kind::Function, "string-append"),
+            (Kind::String, "\"Hi \""),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "if"),
+            (Kind::Literal, "#t"),
+            (Kind::Glyph, "`"),
+            (Kind::Punctuation, "("),
+            (Kind::Glyph, ","),
+            (Kind::Identifier, "s"),
+            (Kind::String, "#\\!"),
+            (Kind::Number, "-1/2"),
+            (Kind::Punctuation, ")"),
+            (Kind::Glyph, "'"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Scheme>(source),
+        concat!(
+            "<span class=\"comment\">#| outer #| inner |# still |#</span>\n",
+            "<span class=\"punctuation\">(</span><span class=\"keyword\">define</span> <span class=\"punctuation\">(</span><span class=\"function\">greet</span> <span class=\"identifier\">name</span><span class=\"punctuation\">)</span> <span class=\"comment\">; say hi</span>\n",
+            "  <span class=\"punctuation\">(</span><span class=\"keyword\">let</span> <span class=\"punctuation\">((</span><span class=\"function\">s</span> <span class=\"punctuation\">(</span><span class=\"function\">string-append</span> <span class=\"string\">&quot;Hi &quot;</span> <span class=\"identifier\">name</span><span class=\"punctuation\">)))</span>\n",
+            "    <span class=\"punctuation\">(</span><span class=\"keyword\">if</span> <span class=\"literal\">#t</span> <span class=\"glyph\">`</span><span class=\"punctuation\">(</span><span class=\"glyph\">,</span><span class=\"identifier\">s</span> <span class=\"string\">#\\!</span> <span class=\"number\">-1/2</span><span class=\"punctuation\">)</span> <span class=\"glyph\">&#39;</span><span class=\"punctuation\">())))</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("racket"), Some(Language::Scheme));
+}
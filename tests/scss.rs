@@ -0,0 +1,68 @@
+#![cfg(feature = "lang-scss")]
+mod common;
+
+use cmark_syntax::languages::Scss;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn scss_highlight() {
+    let source = concat!(
+        "$primary: #336 !default; // brand\n",
+        "@mixin button($size) { padding: $size; }\n",
+        ".nav { color: $primary; @include button(2px); &.#{$state} { margin: 0 } }\n",
+    );
+    assert_eq!(
+        kinds::<Scss>(source),
+        [
+            (Kind::SpecialIdentifier, "$primary"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "#336"),
+            (Kind::Keyword, "!default"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "// brand"),
+            (Kind::Keyword, "@mixin"),
+            (Kind::StrongIdentifier, "button"),
+            (Kind::Punctuation, "("),
+            (Kind::SpecialIdentifier, "$size"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "padding"),
+            (Kind::Punctuation, ":"),
+            (Kind::SpecialIdentifier, "$size"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::SpecialIdentifier, ".nav"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "color"),
+            (Kind::Punctuation, ":"),
+            (Kind::SpecialIdentifier, "$primary"),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "@include"),
+            (Kind::Function, "button"),
+            (Kind::Punctuation, "("),
+            (Kind::Number, "2"),
+            (Kind::Type, "px"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Operator, "&"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "#{$state}"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "margin"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "0"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Scss>(source),
+        concat!(
+            "<span class=\"special-identifier\">$primary</span><span class=\"punctuation\">:</span> <span class=\"number\">#336</span> <span class=\"keyword\">!default</span><span class=\"punctuation\">;</span> <span class=\"comment\">// brand</span>\n",
+            "<span class=\"keyword\">@mixin</span> <span class=\"strong-identifier\">button</span><span class=\"punctuation\">(</span><span class=\"special-identifier\">$size</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">padding</span><span class=\"punctuation\">:</span> <span class=\"special-identifier\">$size</span><span class=\"punctuation\">;</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"special-identifier\">.nav</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">color</span><span class=\"punctuation\">:</span> <span class=\"special-identifier\">$primary</span><span class=\"punctuation\">;</span> <span class=\"keyword\">@include</span> <span class=\"function\">button</span><span class=\"punctuation\">(</span><span class=\"number\">2</span><span class=\"type\">px</span><span class=\"punctuation\">);</span> <span class=\"operator\">&amp;</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">#{$state}</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">margin</span><span class=\"punctuation\">:</span> <span class=\"number\">0</span> <span class=\"punctuation\">}</span> <span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("sass"), Some(Language::Scss));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "lang-toml")]
+#[cfg(feature = "serde")]
+use cmark_syntax::{languages::Toml, tokens_to_json, Kind};
+
+#[cfg(feature = "serde")]
+#[test]
+fn kind_round_trip() {
+    let kinds = [
+        (Kind::None, "\"none\""),
+        (Kind::Glyph, "\"glyph\""),
+        (Kind::Literal, "\"literal\""),
+        (Kind::Identifier, "\"identifier\""),
+        (Kind::SpecialIdentifier, "\"special-identifier\""),
+        (Kind::StrongIdentifier, "\"strong-identifier\""),
+        (Kind::Keyword, "\"keyword\""),
+        (Kind::Comment, "\"comment\""),
+        (Kind::Number, "\"number\""),
+        (Kind::String, "\"string\""),
+        (Kind::Function, "\"function\""),
+        (Kind::Type, "\"type\""),
+        (Kind::Operator, "\"operator\""),
+        (Kind::Punctuation, "\"punctuation\""),
+        (Kind::Inserted, "\"inserted\""),
+        (Kind::Deleted, "\"deleted\""),
+        (Kind::Marker, "\"marker\""),
+    ];
+
+    for (kind, json) in kinds {
+        assert_eq!(serde_json::to_string(&kind).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Kind>(json).unwrap(), kind);
+    }
+    assert!(serde_json::from_str::<Kind>("\"bold\"").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_tokens() {
+    assert_eq!(
+        tokens_to_json::<Toml>("a = \"\\n\""),
+        concat!(
+            r#"[{"kind":"identifier","text":"a"},{"kind":"none","text":" = "},"#,
+            r#"{"kind":"string","text":"\"\\n\""}]"#
+        )
+    );
+}
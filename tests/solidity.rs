@@ -0,0 +1,71 @@
+#![cfg(feature = "lang-solidity")]
+mod common;
+
+use cmark_syntax::languages::Solidity;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn solidity_highlight() {
+    let source = concat!(
+        "/// @title A token\n",
+        "contract Token {\n",
+        "    mapping(address => uint256) balances;\n",
+        "    bytes32 constant TAG = hex\"00ff\";\n",
+        "    function balance() public view returns (uint) { return msg.value; }\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Solidity>(source),
+        [
+            (Kind::Comment, "/// @title A token"),
+            (Kind::Keyword, "contract"),
+            (Kind::SpecialIdentifier, "Token"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "mapping"),
+            (Kind::Punctuation, "("),
+            (Kind::Type, "address"),
+            (Kind::Operator, "=>"),
+            (Kind::Type, "uint256"),
+            (Kind::Punctuation, ")"),
+            (Kind::Identifier, "balances"),
+            (Kind::Punctuation, ";"),
+            (Kind::Type, "bytes32"),
+            (Kind::Keyword, "constant"),
+            (Kind::StrongIdentifier, "TAG"),
+            (Kind::Operator, "="),
+            (Kind::String, "hex\"00ff\""),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "balance"),
+            (Kind::Punctuation, "("),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "public"),
+            (Kind::Keyword, "view"),
+            (Kind::Keyword, "returns"),
+            (Kind::Punctuation, "("),
+            (Kind::Type, "uint"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "return"),
+            (Kind::Identifier, "msg"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "value"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Solidity>(source),
+        concat!(
+            "<span class=\"comment\">/// @title A token</span>\n",
+            "<span class=\"keyword\">contract</span> <span class=\"special-identifier\">Token</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">mapping</span><span class=\"punctuation\">(</span><span class=\"type\">address</span> <span class=\"operator\">=&gt;</span> <span class=\"type\">uint256</span><span class=\"punctuation\">)</span> <span class=\"identifier\">balances</span><span class=\"punctuation\">;</span>\n",
+            "    <span class=\"type\">bytes32</span> <span class=\"keyword\">constant</span> <span class=\"strong-identifier\">TAG</span> <span class=\"operator\">=</span> <span class=\"string\">hex&quot;00ff&quot;</span><span class=\"punctuation\">;</span>\n",
+            "    <span class=\"keyword\">function</span> <span class=\"function\">balance</span><span class=\"punctuation\">()</span> <span class=\"keyword\">public</span> <span class=\"keyword\">view</span> <span class=\"keyword\">returns</span> <span class=\"punctuation\">(</span><span class=\"type\">uint</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">return</span> <span class=\"identifier\">msg</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">value</span><span class=\"punctuation\">;</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("sol"), Some(Language::Solidity));
+}
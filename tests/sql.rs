@@ -0,0 +1,63 @@
+#![cfg(feature = "lang-sql")]
+mod common;
+
+use cmark_syntax::languages::Sql;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn sql_highlight() {
+    let source = concat!(
+        "-- recent posts\n",
+        "SELECT p.\"title\", count(*) FROM posts p\n",
+        "where p.author = 'O''Brien' AND p.views > 1.5 /* hot */ or Selected IS NULL;\n",
+    );
+    assert_eq!(
+        kinds::<Sql>(source),
+        [
+            (Kind::Comment, "-- recent posts"),
+            (Kind::Keyword, "SELECT"),
+            (Kind::Identifier, "p"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "\"title\""),
+            (Kind::Punctuation, ","),
+            (Kind::Function, "count"),
+            (Kind::Punctuation, "("),
+            (Kind::Operator, "*"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "FROM"),
+            (Kind::Identifier, "posts"),
+            (Kind::Identifier, "p"),
+            (Kind::Keyword, "where"),
+            (Kind::Identifier, "p"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "author"),
+            (Kind::Operator, "="),
+            (Kind::String, "'O''Brien'"),
+            (Kind::Keyword, "AND"),
+            (Kind::Identifier, "p"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "views"),
+            (Kind::Operator, ">"),
+            (Kind::Number, "1.5"),
+            (Kind::Comment, "/* hot */"),
+            (Kind::Keyword, "or"),
+            (Kind::Identifier, "Selected"),
+            (Kind::Keyword, "IS"),
+            (Kind::Literal, "NULL"),
+            (Kind::Punctuation, ";"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Sql>(source),
+        concat!(
+            "<span class=\"comment\">-- recent posts</span>\n",
+            "<span class=\"keyword\">SELECT</span> <span class=\"identifier\">p</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">&quot;title&quot;</span><span class=\"punctuation\">,</span> <span class=\"function\">count</span><span class=\"punctuation\">(</span><span class=\"operator\">*</span><span class=\"punctuation\">)</span> <span class=\"keyword\">FROM</span> <span class=\"identifier\">posts</span> <span class=\"identifier\">p</span>\n",
+            "<span class=\"keyword\">where</span> <span class=\"identifier\">p</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">author</span> <span class=\"operator\">=</span> <span class=\"string\">&#39;O&#39;&#39;Brien&#39;</span> <span class=\"keyword\">AND</span> <span class=\"identifier\">p</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">views</span> <span class=\"operator\">&gt;</span> <span class=\"number\">1.5</span> <span class=\"comment\">/* hot */</span> <span class=\"keyword\">or</span> <span class=\"identifier\">Selected</span> <span class=\"keyword\">IS</span> <span class=\"literal\">NULL</span><span class=\"punctuation\">;</span>\n",
+        )
+    );
+    for select in ["select", "SELECT", "Select"] {
+        assert_eq!(kinds::<Sql>(select), [(Kind::Keyword, select)]);
+    }
+    assert_eq!(Language::from_name("sql"), Some(Language::Sql));
+}
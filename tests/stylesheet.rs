@@ -0,0 +1,36 @@
+use cmark_syntax::{dark_stylesheet, default_stylesheet, light_stylesheet, Kind, TagMap};
+
+const KINDS: &[Kind] = &[
+    Kind::Glyph,
+    Kind::Literal,
+    Kind::Identifier,
+    Kind::SpecialIdentifier,
+    Kind::StrongIdentifier,
+    Kind::Keyword,
+    Kind::Comment,
+    Kind::Number,
+    Kind::String,
+    Kind::Function,
+    Kind::Type,
+    Kind::Operator,
+    Kind::Punctuation,
+    Kind::Inserted,
+    Kind::Deleted,
+    Kind::Marker,
+];
+
+#[test]
+fn every_class() {
+    assert_eq!(default_stylesheet(), light_stylesheet());
+    for stylesheet in [light_stylesheet(), dark_stylesheet()] {
+        for &kind in KINDS {
+            let class = TagMap::CLASSES.get(kind).unwrap();
+            assert_eq!(class, kind.name());
+            assert!(
+                stylesheet.contains(&format!("code .{} {{", class)),
+                "{}",
+                class
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "lang-swift")]
+mod common;
+
+use cmark_syntax::languages::Swift;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn swift_highlight() {
+    let source = concat!(
+        "/* outer /* inner */ still outer */\n",
+        "@available(iOS 13, *)\n",
+        "func greet(name: String?) -> String {\n",
+        "    guard let name = name else { return \"nobody\" }\n",
+        "    return \"Hi \\(name.count)!\" // done\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Swift>(source),
+        [
+            (Kind::Comment, "/* outer /* inner */ still outer */"),
+            (Kind::Glyph, "@available"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "iOS"),
+            (Kind::Number, "13"),
+            (Kind::Punctuation, ","),
+            (Kind::Operator, "*"),
+            (Kind::Punctuation, ")"),
+            (Kind::Keyword, "func"),
+            (Kind::Function, "greet"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Operator, "?"),
+            (Kind::Punctuation, ")"),
+            (Kind::Operator, "->"),
+            (Kind::StrongIdentifier, "String"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "guard"),
+            (Kind::Keyword, "let"),
+            (Kind::Identifier, "name"),
+            (Kind::Operator, "="),
+            (Kind::Identifier, "name"),
+            (Kind::Keyword, "else"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "\"nobody\""),
+            (Kind::Punctuation, "}"),
+            (Kind::Keyword, "return"),
+            (Kind::String, "\"Hi \\(name.count)!\""),
+            (Kind::Comment, "// done"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Swift>(source),
+        concat!(
+            "<span class=\"comment\">/* outer /* inner */ still outer */</span>\n",
+            "<span class=\"glyph\">@available</span><span class=\"punctuation\">(</span><span class=\"identifier\">iOS</span> <span class=\"number\">13</span><span class=\"punctuation\">,</span> <span class=\"operator\">*</span><span class=\"punctuation\">)</span>\n",
+            "<span class=\"keyword\">func</span> <span class=\"function\">greet</span><span class=\"punctuation\">(</span><span class=\"identifier\">name</span><span class=\"punctuation\">:</span> <span class=\"strong-identifier\">String</span><span class=\"operator\">?</span><span class=\"punctuation\">)</span> <span class=\"operator\">-&gt;</span> <span class=\"strong-identifier\">String</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">guard</span> <span class=\"keyword\">let</span> <span class=\"identifier\">name</span> <span class=\"operator\">=</span> <span class=\"identifier\">name</span> <span class=\"keyword\">else</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">return</span> <span class=\"string\">&quot;nobody&quot;</span> <span class=\"punctuation\">}</span>\n",
+            "    <span class=\"keyword\">return</span> <span class=\"string\">&quot;Hi \\(name.count)!&quot;</span> <span class=\"comment\">// done</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(kinds::<Swift>("1_000_000"), [(Kind::Number, "1_000_000")]);
+    assert_eq!(Language::from_name("swift"), Some(Language::Swift));
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+#[cfg(feature = "syntect")]
+use cmark_syntax::{Language, SyntaxPreprocessor};
+#[cfg(feature = "syntect")]
+use pulldown_cmark::{html, Parser};
+
+#[cfg(feature = "syntect")]
+#[test]
+fn csharp_fallback() {
+    let parser = Parser::new("```cs\nint F(int x) {\n    return x + 1; // one\n}\n```");
+    let processed = SyntaxPreprocessor::new(parser);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, processed);
+    // There is no built-in C# lexer, so the code is highlighted by syntect,
+    // whose comment scopes include the line ending
+    assert_eq!(Language::from_name("cs"), None);
+    assert_eq!(
+        rendered,
+        concat!(
+            r#"<pre><code class="language-cs"><span class="keyword">int</span> "#,
+            r#"<span class="function">F</span><span class="punctuation">(</span>"#,
+            r#"<span class="keyword">int</span> <span class="identifier">x</span>"#,
+            r#"<span class="punctuation">)</span> <span class="punctuation">{</span>"#,
+            "\n    ",
+            r#"<span class="keyword">return</span> <span class="identifier">x</span> "#,
+            r#"<span class="operator">+</span> <span class="number">1</span>"#,
+            r#"<span class="punctuation">;</span> <span class="comment">// one"#,
+            "\n",
+            r#"</span><span class="punctuation">}</span></code></pre>"#,
+        )
+    );
+}
@@ -0,0 +1,25 @@
+#include <stdio.h>
+#define SQUARE(x) ((x) * (x))
+
+/* A point in the plane */
+typedef struct point {
+    double x, y;
+} point_t;
+
+static const char *names[] = { "origin", "unit" };
+
+int main(void) {
+    struct point p = { .x = 1.0, .y = 0x10 };
+    unsigned long count = 0;
+
+    // Count to ten
+    for (int i = 0; i < 10; i++) {
+        count += SQUARE(i);
+    }
+
+    if (p.x > 0 && names[0] != NULL) {
+        printf("%s: %lu\n", names[0], count);
+    }
+
+    return sizeof(p) == 16 ? 0 : 'e';
+}
@@ -1,3 +1,4 @@
+#![cfg(all(feature = "lang-toml", feature = "std"))]
 use cmark_syntax::SyntaxPreprocessor;
 use pulldown_cmark::{html, Parser};
 
@@ -0,0 +1,49 @@
+#![cfg(all(feature = "lang-json", feature = "std"))]
+#[cfg(feature = "tree-sitter")]
+use cmark_syntax::tree_sitter::Grammar;
+#[cfg(feature = "tree-sitter")]
+use cmark_syntax::{Kind, SyntaxConfig, SyntaxPreprocessor};
+#[cfg(feature = "tree-sitter")]
+use pulldown_cmark::{html, Parser};
+
+#[cfg(feature = "tree-sitter")]
+fn render(config: SyntaxConfig, markdown: &str) -> String {
+    let mut rendered = String::new();
+    let processed = SyntaxPreprocessor::with_config(Parser::new(markdown), config);
+    html::push_html(&mut rendered, processed);
+    rendered
+}
+
+#[cfg(feature = "tree-sitter")]
+#[test]
+fn json_grammar() {
+    let grammar = Grammar::new(
+        tree_sitter_json::LANGUAGE.into(),
+        "json",
+        tree_sitter_json::HIGHLIGHTS_QUERY,
+    )
+    .unwrap();
+    let config = SyntaxConfig::new().with_grammar("JSON", grammar);
+
+    let rendered = render(config, "```json\n{\"a\": 1, \"b\": null}\n```");
+    assert!(rendered.contains("<span class=\"number\">1</span>"));
+    assert!(rendered.contains("<span class=\"literal\">null</span>"));
+}
+
+#[cfg(feature = "tree-sitter")]
+#[test]
+fn custom_captures() {
+    let grammar = Grammar::with_captures(
+        tree_sitter_json::LANGUAGE.into(),
+        "json",
+        tree_sitter_json::HIGHLIGHTS_QUERY,
+        &[("number", Kind::Keyword)],
+    )
+    .unwrap();
+    let config = SyntaxConfig::new().with_grammar("json", grammar);
+
+    assert_eq!(
+        render(config, "```json\n[1, \"a\"]\n```"),
+        "<pre><code class=\"language-json\">[<span class=\"keyword\">1</span>, &quot;a&quot;]</code></pre>"
+    );
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "lang-ts")]
+mod common;
+
+use cmark_syntax::languages::TypeScript;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn typescript_highlight() {
+    let source = concat!(
+        "interface Point { x: number; tag?: Tag }\n",
+        "type Id = string | null; // alias\n",
+        "@sealed\n",
+        "function norm(p: Point): number { return p.x as number; }\n",
+    );
+    assert_eq!(
+        kinds::<TypeScript>(source),
+        [
+            (Kind::Keyword, "interface"),
+            (Kind::SpecialIdentifier, "Point"),
+            (Kind::Punctuation, "{"),
+            (Kind::Identifier, "x"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "number"),
+            (Kind::Punctuation, ";"),
+            (Kind::Identifier, "tag"),
+            (Kind::Operator, "?"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "Tag"),
+            (Kind::Punctuation, "}"),
+            (Kind::Keyword, "type"),
+            (Kind::SpecialIdentifier, "Id"),
+            (Kind::Operator, "="),
+            (Kind::Type, "string"),
+            (Kind::Operator, "|"),
+            (Kind::Literal, "null"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "// alias"),
+            (Kind::Glyph, "@sealed"),
+            (Kind::Keyword, "function"),
+            (Kind::Function, "norm"),
+            (Kind::Punctuation, "("),
+            (Kind::Identifier, "p"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "Point"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "number"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "return"),
+            (Kind::Identifier, "p"),
+            (Kind::Punctuation, "."),
+            (Kind::SpecialIdentifier, "x"),
+            (Kind::Keyword, "as"),
+            (Kind::Type, "number"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<TypeScript>(source),
+        concat!(
+            "<span class=\"keyword\">interface</span> <span class=\"special-identifier\">Point</span> <span class=\"punctuation\">{</span> <span class=\"identifier\">x</span><span class=\"punctuation\">:</span> <span class=\"type\">number</span><span class=\"punctuation\">;</span> <span class=\"identifier\">tag</span><span class=\"operator\">?</span><span class=\"punctuation\">:</span> <span class=\"type\">Tag</span> <span class=\"punctuation\">}</span>\n",
+            "<span class=\"keyword\">type</span> <span class=\"special-identifier\">Id</span> <span class=\"operator\">=</span> <span class=\"type\">string</span> <span class=\"operator\">|</span> <span class=\"literal\">null</span><span class=\"punctuation\">;</span> <span class=\"comment\">// alias</span>\n",
+            "<span class=\"glyph\">@sealed</span>\n",
+            "<span class=\"keyword\">function</span> <span class=\"function\">norm</span><span class=\"punctuation\">(</span><span class=\"identifier\">p</span><span class=\"punctuation\">:</span> <span class=\"type\">Point</span><span class=\"punctuation\">):</span> <span class=\"type\">number</span> <span class=\"punctuation\">{</span> <span class=\"keyword\">return</span> <span class=\"identifier\">p</span><span class=\"punctuation\">.</span><span class=\"special-identifier\">x</span> <span class=\"keyword\">as</span> <span class=\"type\">number</span><span class=\"punctuation\">;</span> <span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<TypeScript>("`a ${b}`"),
+        [(Kind::String, "`a ${b}`")]
+    );
+    assert_eq!(Language::from_name("tsx"), Some(Language::TypeScript));
+}
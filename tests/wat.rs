@@ -0,0 +1,60 @@
+#![cfg(feature = "lang-wat")]
+mod common;
+
+use cmark_syntax::languages::Wat;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn wat_highlight() {
+    let source = concat!(
+        "(module (; outer (; inner ;) ;)\n",
+        "  (func $add (export \"add\") (param $a i32) (result i32)\n",
+        "    local.get $a\n",
+        "    i32.const -1 ;; minus one\n",
+        "    i32.add))\n",
+    );
+    assert_eq!(
+        kinds::<Wat>(source),
+        [
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "module"),
+            (Kind::Comment, "(; outer (; inner ;) ;)"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "func"),
+            (Kind::Identifier, "$add"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "export"),
+            (Kind::String, "\"add\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "param"),
+            (Kind::Identifier, "$a"),
+            (Kind::Type, "i32"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "result"),
+            (Kind::Type, "i32"),
+            (Kind::Punctuation, ")"),
+            (Kind::Function, "local.get"),
+            (Kind::Identifier, "$a"),
+            (Kind::Function, "i32.const"),
+            (Kind::Number, "-1"),
+            (Kind::Comment, ";; minus one"),
+            (Kind::Function, "i32.add"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ")"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Wat>(source),
+        concat!(
+            "<span class=\"punctuation\">(</span><span class=\"keyword\">module</span> <span class=\"comment\">(; outer (; inner ;) ;)</span>\n",
+            "  <span class=\"punctuation\">(</span><span class=\"keyword\">func</span> <span class=\"identifier\">$add</span> <span class=\"punctuation\">(</span><span class=\"keyword\">export</span> <span class=\"string\">&quot;add&quot;</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">(</span><span class=\"keyword\">param</span> <span class=\"identifier\">$a</span> <span class=\"type\">i32</span><span class=\"punctuation\">)</span> <span class=\"punctuation\">(</span><span class=\"keyword\">result</span> <span class=\"type\">i32</span><span class=\"punctuation\">)</span>\n",
+            "    <span class=\"function\">local.get</span> <span class=\"identifier\">$a</span>\n",
+            "    <span class=\"function\">i32.const</span> <span class=\"number\">-1</span> <span class=\"comment\">;; minus one</span>\n",
+            "    <span class=\"function\">i32.add</span><span class=\"punctuation\">))</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("wasm"), Some(Language::Wat));
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "lang-xml")]
+mod common;
+
+use cmark_syntax::languages::Xml;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn xml_highlight() {
+    let source = concat!(
+        "<?xml version=\"1.0\"?>\n",
+        "<!-- notes -->\n",
+        "<note xml:lang='en'>a &amp; b > c<![CDATA[<raw> ]] ]]><br/></note>\n",
+    );
+    assert_eq!(
+        kinds::<Xml>(source),
+        [
+            (Kind::Punctuation, "<?"),
+            (Kind::Keyword, "xml"),
+            (Kind::Identifier, "version"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "\"1.0\""),
+            (Kind::Punctuation, "?>"),
+            (Kind::Comment, "<!-- notes -->"),
+            (Kind::Punctuation, "<"),
+            (Kind::Keyword, "note"),
+            (Kind::Identifier, "xml:lang"),
+            (Kind::Punctuation, "="),
+            (Kind::String, "'en'"),
+            (Kind::Punctuation, ">"),
+            (Kind::Literal, "&amp;"),
+            (Kind::String, "<![CDATA[<raw> ]] ]]>"),
+            (Kind::Punctuation, "<"),
+            (Kind::Keyword, "br"),
+            (Kind::Punctuation, "/>"),
+            (Kind::Punctuation, "</"),
+            (Kind::Keyword, "note"),
+            (Kind::Punctuation, ">"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Xml>(source),
+        concat!(
+            "<span class=\"punctuation\">&lt;?</span><span class=\"keyword\">xml</span> <span class=\"identifier\">version</span><span class=\"punctuation\">=</span><span class=\"string\">&quot;1.0&quot;</span><span class=\"punctuation\">?&gt;</span>\n",
+            "<span class=\"comment\">&lt;!-- notes --&gt;</span>\n",
+            "<span class=\"punctuation\">&lt;</span><span class=\"keyword\">note</span> <span class=\"identifier\">xml:lang</span><span class=\"punctuation\">=</span><span class=\"string\">&#39;en&#39;</span><span class=\"punctuation\">&gt;</span>a <span class=\"literal\">&amp;amp;</span> b &gt; c<span class=\"string\">&lt;![CDATA[&lt;raw&gt; ]] ]]&gt;</span><span class=\"punctuation\">&lt;</span><span class=\"keyword\">br</span><span class=\"punctuation\">/&gt;&lt;/</span><span class=\"keyword\">note</span><span class=\"punctuation\">&gt;</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("xml"), Some(Language::Xml));
+}
+
+#[test]
+fn xml_escaping() {
+    assert_eq!(
+        highlight_to_string::<Xml>("<a b=\"c\"/><!-- d -->"),
+        concat!(
+            r#"<span class="punctuation">&lt;</span><span class="keyword">a</span> "#,
+            r#"<span class="identifier">b</span><span class="punctuation">=</span>"#,
+            r#"<span class="string">&quot;c&quot;</span><span class="punctuation">/&gt;</span>"#,
+            r#"<span class="comment">&lt;!-- d --&gt;</span>"#,
+        )
+    );
+}
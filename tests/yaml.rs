@@ -0,0 +1,84 @@
+#![cfg(feature = "lang-yaml")]
+mod common;
+
+use cmark_syntax::languages::Yaml;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn yaml_highlight() {
+    let source = concat!(
+        "# settings\n",
+        "name: cmark syntax\n",
+        "base: &base\n",
+        "  port: 80\n",
+        "  url: http://example.com\n",
+        "items:\n",
+        "  - id: !!str 1\n",
+        "  - *base\n",
+        "text: |\n",
+        "  plain text\n",
+        "flow: {a, b: [c]}\n",
+    );
+    assert_eq!(
+        kinds::<Yaml>(source),
+        [
+            (Kind::Comment, "# settings"),
+            (Kind::Identifier, "name"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "cmark syntax"),
+            (Kind::Identifier, "base"),
+            (Kind::Punctuation, ":"),
+            (Kind::SpecialIdentifier, "&base"),
+            (Kind::Identifier, "port"),
+            (Kind::Punctuation, ":"),
+            (Kind::Number, "80"),
+            (Kind::Identifier, "url"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "http"),
+            (Kind::Punctuation, ":"),
+            (Kind::String, "//example.com"),
+            (Kind::Identifier, "items"),
+            (Kind::Punctuation, ":"),
+            (Kind::Punctuation, "- "),
+            (Kind::Identifier, "id"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "!!str"),
+            (Kind::Number, "1"),
+            (Kind::Punctuation, "- "),
+            (Kind::SpecialIdentifier, "*base"),
+            (Kind::Identifier, "text"),
+            (Kind::Punctuation, ":"),
+            (Kind::Glyph, "|"),
+            (Kind::String, "plain text"),
+            (Kind::Identifier, "flow"),
+            (Kind::Punctuation, ":"),
+            (Kind::Punctuation, "{"),
+            (Kind::String, "a"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "b"),
+            (Kind::Punctuation, ":"),
+            (Kind::Punctuation, "["),
+            (Kind::String, "c"),
+            (Kind::Punctuation, "]"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Yaml>(source),
+        concat!(
+            "<span class=\"comment\"># settings</span>\n",
+            "<span class=\"identifier\">name</span><span class=\"punctuation\">:</span> <span class=\"string\">cmark syntax</span>\n",
+            "<span class=\"identifier\">base</span><span class=\"punctuation\">:</span> <span class=\"special-identifier\">&amp;base</span>\n",
+            "  <span class=\"identifier\">port</span><span class=\"punctuation\">:</span> <span class=\"number\">80</span>\n",
+            "  <span class=\"identifier\">url</span><span class=\"punctuation\">:</span> <span class=\"string\">http</span><span class=\"punctuation\">:</span><span class=\"string\">//example.com</span>\n",
+            "<span class=\"identifier\">items</span><span class=\"punctuation\">:</span>\n",
+            "  <span class=\"punctuation\">- </span><span class=\"identifier\">id</span><span class=\"punctuation\">:</span> <span class=\"type\">!!str</span> <span class=\"number\">1</span>\n",
+            "  <span class=\"punctuation\">- </span><span class=\"special-identifier\">*base</span>\n",
+            "<span class=\"identifier\">text</span><span class=\"punctuation\">:</span> <span class=\"glyph\">|</span>\n",
+            "  <span class=\"string\">plain text</span>\n",
+            "<span class=\"identifier\">flow</span><span class=\"punctuation\">:</span> <span class=\"punctuation\">{</span><span class=\"string\">a</span><span class=\"punctuation\">,</span> <span class=\"identifier\">b</span><span class=\"punctuation\">:</span> <span class=\"punctuation\">[</span><span class=\"string\">c</span><span class=\"punctuation\">]}</span>\n",
+        )
+    );
+    assert_eq!(Language::from_name("yml"), Some(Language::Yaml));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "lang-zig")]
+mod common;
+
+use cmark_syntax::languages::Zig;
+use cmark_syntax::{highlight_to_string, Kind, Language};
+use common::kinds;
+
+#[test]
+fn zig_highlight() {
+    let source = concat!(
+        "const std = @import(\"std\");\n",
+        "/// Doubles a number.\n",
+        "pub fn double(comptime T: type, x: T) T {\n",
+        "    const text =\n",
+        "        \\\\multiline \"text\"\n",
+        "    ;\n",
+        "    return x * @intCast(1_000);\n",
+        "}\n",
+    );
+    assert_eq!(
+        kinds::<Zig>(source),
+        [
+            (Kind::Keyword, "const"),
+            (Kind::Identifier, "std"),
+            (Kind::Operator, "="),
+            (Kind::SpecialIdentifier, "@import"),
+            (Kind::Punctuation, "("),
+            (Kind::String, "\"std\""),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Comment, "/// Doubles a number."),
+            (Kind::Keyword, "pub"),
+            (Kind::Keyword, "fn"),
+            (Kind::Function, "double"),
+            (Kind::Punctuation, "("),
+            (Kind::Keyword, "comptime"),
+            (Kind::StrongIdentifier, "T"),
+            (Kind::Punctuation, ":"),
+            (Kind::Type, "type"),
+            (Kind::Punctuation, ","),
+            (Kind::Identifier, "x"),
+            (Kind::Punctuation, ":"),
+            (Kind::StrongIdentifier, "T"),
+            (Kind::Punctuation, ")"),
+            (Kind::StrongIdentifier, "T"),
+            (Kind::Punctuation, "{"),
+            (Kind::Keyword, "const"),
+            (Kind::Identifier, "text"),
+            (Kind::Operator, "="),
+            (Kind::String, "\\\\multiline \"text\""),
+            (Kind::Punctuation, ";"),
+            (Kind::Keyword, "return"),
+            (Kind::Identifier, "x"),
+            (Kind::Operator, "*"),
+            (Kind::SpecialIdentifier, "@intCast"),
+            (Kind::Punctuation, "("),
+            (Kind::Number, "1_000"),
+            (Kind::Punctuation, ")"),
+            (Kind::Punctuation, ";"),
+            (Kind::Punctuation, "}"),
+        ]
+    );
+    assert_eq!(
+        highlight_to_string::<Zig>(source),
+        concat!(
+            "<span class=\"keyword\">const</span> <span class=\"identifier\">std</span> <span class=\"operator\">=</span> <span class=\"special-identifier\">@import</span><span class=\"punctuation\">(</span><span class=\"string\">&quot;std&quot;</span><span class=\"punctuation\">);</span>\n",
+            "<span class=\"comment\">/// Doubles a number.</span>\n",
+            "<span class=\"keyword\">pub</span> <span class=\"keyword\">fn</span> <span class=\"function\">double</span><span class=\"punctuation\">(</span><span class=\"keyword\">comptime</span> <span class=\"strong-identifier\">T</span><span class=\"punctuation\">:</span> <span class=\"type\">type</span><span class=\"punctuation\">,</span> <span class=\"identifier\">x</span><span class=\"punctuation\">:</span> <span class=\"strong-identifier\">T</span><span class=\"punctuation\">)</span> <span class=\"strong-identifier\">T</span> <span class=\"punctuation\">{</span>\n",
+            "    <span class=\"keyword\">const</span> <span class=\"identifier\">text</span> <span class=\"operator\">=</span>\n",
+            "        <span class=\"string\">\\\\multiline &quot;text&quot;</span>\n",
+            "    <span class=\"punctuation\">;</span>\n",
+            "    <span class=\"keyword\">return</span> <span class=\"identifier\">x</span> <span class=\"operator\">*</span> <span class=\"special-identifier\">@intCast</span><span class=\"punctuation\">(</span><span class=\"number\">1_000</span><span class=\"punctuation\">);</span>\n",
+            "<span class=\"punctuation\">}</span>\n",
+        )
+    );
+    assert_eq!(
+        kinds::<Zig>("u8 i"),
+        [(Kind::Type, "u8"), (Kind::Identifier, "i")]
+    );
+    assert_eq!(Language::from_name("zig"), Some(Language::Zig));
+}